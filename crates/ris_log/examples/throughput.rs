@@ -0,0 +1,68 @@
+// measures how many messages per second can be logged from multiple threads at once
+//
+//     cargo run -r --example throughput --features logging_enabled
+
+use std::time::Instant;
+
+use ris_log::log::IAppender;
+use ris_log::log_filter::LogFilter;
+use ris_log::log_level::LogLevel;
+use ris_log::log_message::LogMessage;
+
+const THREADS: usize = 12;
+const MESSAGES_PER_THREAD: usize = 100_000;
+
+struct NullAppender;
+
+impl IAppender for NullAppender {
+    fn print(&mut self, _message: &LogMessage) {}
+}
+
+fn main() {
+    let log_guard = ris_log::log::init(LogLevel::Info, vec![Box::new(NullAppender)]);
+
+    run("enabled", |i| ris_log::info!("message {}", i));
+    run("filtered", |i| ris_log::trace!("message {}", i));
+
+    // with directives, the level depends on where a message is logged
+    match LogFilter::parse("other_package=trace,info") {
+        Ok(filter) => ris_log::log::set_filter(filter),
+        Err(e) => panic!("invalid filter: {}", e),
+    }
+
+    run("enabled with directives", |i| {
+        ris_log::info!("message {}", i)
+    });
+    run("filtered with directives", |i| {
+        ris_log::trace!("message {}", i)
+    });
+
+    drop(log_guard);
+}
+
+fn run(label: &str, log: impl Fn(usize) + Sync) {
+    let start = Instant::now();
+
+    std::thread::scope(|s| {
+        for _ in 0..THREADS {
+            s.spawn(|| {
+                for i in 0..MESSAGES_PER_THREAD {
+                    log(i);
+                }
+            });
+        }
+    });
+
+    ris_log::log::flush();
+
+    let elapsed = start.elapsed();
+    let messages = THREADS * MESSAGES_PER_THREAD;
+    println!(
+        "{}: {} messages from {} threads in {:.1?}, {:.0} messages/s",
+        label,
+        messages,
+        THREADS,
+        elapsed,
+        messages as f64 / elapsed.as_secs_f64(),
+    );
+}
@@ -0,0 +1,181 @@
+use std::cell::RefCell;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+
+use crate::constructed_log_message::ConstructedLogMessage;
+use crate::file_appender::DEFAULT_FORMAT_ARGS;
+use crate::log::AppenderId;
+use crate::log::IAppender;
+use crate::log::LogGuard;
+use crate::log_level::LogLevel;
+use crate::log_message::LogMessage;
+
+pub type CaptureBuffer = Arc<Mutex<Vec<ConstructedLogMessage>>>;
+
+// statics are never dropped, which keeps a log initialized by `start` alive
+static LOG_GUARD: OnceLock<Option<LogGuard>> = OnceLock::new();
+
+thread_local! {
+    static CURRENT: RefCell<Option<CaptureBuffer>> = const { RefCell::new(None) };
+}
+
+// keeps messages in memory, such that they can be inspected, i.e. by tests. if a thread id is
+// given, only messages of that thread are kept
+pub struct CaptureAppender {
+    pub thread_id: Option<u64>,
+    buffer: CaptureBuffer,
+}
+
+impl CaptureAppender {
+    pub fn new(thread_id: Option<u64>) -> Self {
+        Self {
+            thread_id,
+            buffer: CaptureBuffer::default(),
+        }
+    }
+
+    pub fn buffer(&self) -> CaptureBuffer {
+        self.buffer.clone()
+    }
+}
+
+impl IAppender for CaptureAppender {
+    fn print(&mut self, message: &LogMessage) {
+        let LogMessage::Constructed(message) = message else {
+            return;
+        };
+
+        if self.thread_id.is_some_and(|x| x != message.thread_id) {
+            return;
+        }
+
+        match self.buffer.lock() {
+            Ok(mut buffer) => buffer.push(message.clone()),
+            Err(e) => eprintln!("error while capturing log message: {}", e),
+        }
+    }
+}
+
+// captures all messages logged on the current thread, until the returned guard is dropped.
+// messages of other threads are ignored, such that tests running in parallel don't see each
+// others messages. if the log is not initialized yet, it is initialized at trace level and kept
+// alive for the rest of the process
+#[must_use]
+pub fn start() -> Capture {
+    LOG_GUARD.get_or_init(|| {
+        if crate::log::filter().is_some() {
+            return None;
+        }
+
+        Some(crate::log::init(LogLevel::Trace, Vec::new()))
+    });
+
+    let appender = CaptureAppender::new(Some(crate::thread::id()));
+    let buffer = appender.buffer();
+    let id = crate::log::add_appender(Box::new(appender));
+    let previous = CURRENT.with(|x| x.replace(Some(buffer.clone())));
+
+    Capture {
+        id,
+        buffer,
+        previous,
+    }
+}
+
+pub struct Capture {
+    id: Option<AppenderId>,
+    buffer: CaptureBuffer,
+    previous: Option<CaptureBuffer>,
+}
+
+impl Capture {
+    // waits for all pending messages, before returning the captured ones
+    pub fn messages(&self) -> Vec<ConstructedLogMessage> {
+        crate::log::flush();
+
+        match self.buffer.lock() {
+            Ok(buffer) => buffer.clone(),
+            Err(e) => {
+                eprintln!("error while reading captured messages: {}", e);
+                Vec::new()
+            }
+        }
+    }
+}
+
+impl Drop for Capture {
+    fn drop(&mut self) {
+        if let Some(id) = self.id {
+            crate::log::remove_appender(id);
+        }
+
+        let previous = self.previous.take();
+        CURRENT.with(|x| *x.borrow_mut() = previous);
+    }
+}
+
+// the messages captured by the innermost capture of the current thread
+pub fn messages() -> Vec<ConstructedLogMessage> {
+    let Some(buffer) = CURRENT.with(|x| x.borrow().clone()) else {
+        panic!(
+            "no capture was started on this thread. call `ris_log::capture_appender::start` first"
+        );
+    };
+
+    crate::log::flush();
+
+    let messages = match buffer.lock() {
+        Ok(buffer) => buffer.clone(),
+        Err(e) => {
+            eprintln!("error while reading captured messages: {}", e);
+            Vec::new()
+        }
+    };
+
+    messages
+}
+
+pub fn logged(priority: LogLevel, predicate: impl Fn(&ConstructedLogMessage) -> bool) -> bool {
+    messages()
+        .iter()
+        .any(|x| x.priority == priority && predicate(x))
+}
+
+// all captured messages, one per line. used to explain failed assertions
+pub fn fmt_messages() -> String {
+    let messages = messages();
+    if messages.is_empty() {
+        return String::from("    (nothing)");
+    }
+
+    messages
+        .iter()
+        .map(|x| format!("    {}", x.fmt(DEFAULT_FORMAT_ARGS).replace('\n', "\n    ")))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// usage:
+//
+//     let _capture = ris_log::capture_appender::start();
+//     ...
+//     ris_log::assert_logged!(Warning);
+//     ris_log::assert_logged!(Warning, contains "backtrace");
+#[macro_export]
+macro_rules! assert_logged {
+    ($priority:ident) => {
+        ris_log::assert_logged!($priority, contains "")
+    };
+    ($priority:ident, contains $text:expr) => {{
+        let priority = ris_log::log_level::LogLevel::$priority;
+        let text: &str = $text.as_ref();
+        assert!(
+            ris_log::capture_appender::logged(priority, |x| x.message.contains(text)),
+            "expected a {:?} message containing {:?}, but captured:\n{}",
+            priority,
+            text,
+            ris_log::capture_appender::fmt_messages(),
+        );
+    }};
+}
@@ -1,5 +1,8 @@
 // https://en.wikipedia.org/wiki/ANSI_escape_code
 
+use std::io::IsTerminal;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Color {
     Black,
     Red,
@@ -17,35 +20,198 @@ pub enum Color {
     BrightMagenta,
     BrightCyan,
     BrightWhite,
+    // 0 to 15 are the colors above, 16 to 231 a 6x6x6 cube and 232 to 255 a grayscale ramp
+    Ansi256(u8),
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    fn code(self, background: bool) -> String {
+        let basic = |code: u8| {
+            let code = if background { code + 10 } else { code };
+            code.to_string()
+        };
+        let extended = if background { "48" } else { "38" };
+
+        match self {
+            Color::Black => basic(30),
+            Color::Red => basic(31),
+            Color::Green => basic(32),
+            Color::Yellow => basic(33),
+            Color::Blue => basic(34),
+            Color::Magenta => basic(35),
+            Color::Cyan => basic(36),
+            Color::White => basic(37),
+            Color::BrightBlack => basic(90),
+            Color::BrightRed => basic(91),
+            Color::BrightGreen => basic(92),
+            Color::BrightYellow => basic(93),
+            Color::BrightBlue => basic(94),
+            Color::BrightMagenta => basic(95),
+            Color::BrightCyan => basic(96),
+            Color::BrightWhite => basic(97),
+            Color::Ansi256(index) => format!("{};5;{}", extended, index),
+            Color::Rgb(r, g, b) => format!("{};2;{};{};{}", extended, r, g, b),
+        }
+    }
+}
+
+// styles compose, i.e. `Style::new().foreground(Color::White).background(Color::Red).bold()`.
+// attributes that are not set are left as they are
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Style {
+    pub foreground: Option<Color>,
+    pub background: Option<Color>,
+    pub bold: bool,
+    pub dim: bool,
+    pub italic: bool,
+    pub underline: bool,
+}
+
+impl Style {
+    pub const fn new() -> Self {
+        Self {
+            foreground: None,
+            background: None,
+            bold: false,
+            dim: false,
+            italic: false,
+            underline: false,
+        }
+    }
+
+    pub const fn foreground(mut self, color: Color) -> Self {
+        self.foreground = Some(color);
+        self
+    }
+
+    pub const fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    pub const fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    pub const fn dim(mut self) -> Self {
+        self.dim = true;
+        self
+    }
+
+    pub const fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    pub const fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    // applies `other` on top of this style. colors of `other` replace the ones of this style,
+    // attributes are added
+    pub const fn merge(self, other: Style) -> Self {
+        Self {
+            foreground: match other.foreground {
+                Some(color) => Some(color),
+                None => self.foreground,
+            },
+            background: match other.background {
+                Some(color) => Some(color),
+                None => self.background,
+            },
+            bold: self.bold || other.bold,
+            dim: self.dim || other.dim,
+            italic: self.italic || other.italic,
+            underline: self.underline || other.underline,
+        }
+    }
+
+    // the parameters of the select graphic rendition sequence, i.e. `1;97;41`
+    fn codes(&self) -> String {
+        let mut codes = Vec::new();
+
+        if self.bold {
+            codes.push(String::from("1"));
+        }
+        if self.dim {
+            codes.push(String::from("2"));
+        }
+        if self.italic {
+            codes.push(String::from("3"));
+        }
+        if self.underline {
+            codes.push(String::from("4"));
+        }
+        if let Some(color) = self.foreground {
+            codes.push(color.code(false));
+        }
+        if let Some(color) = self.background {
+            codes.push(color.code(true));
+        }
+
+        codes.join(";")
+    }
+}
+
+impl From<Color> for Style {
+    fn from(value: Color) -> Self {
+        Style::new().foreground(value)
+    }
 }
 
 pub struct ColorString<'a>(pub &'a str, pub Color);
 
 impl ColorString<'_> {
     pub fn fmt(&self, ansi_support: bool) -> String {
-        if !ansi_support {
+        StyledString(self.0, Style::from(self.1)).fmt(ansi_support)
+    }
+}
+
+pub struct StyledString<'a>(pub &'a str, pub Style);
+
+impl StyledString<'_> {
+    pub fn fmt(&self, ansi_support: bool) -> String {
+        let codes = self.1.codes();
+        if !ansi_support || codes.is_empty() || self.0.is_empty() {
             self.0.to_string()
         } else {
-            let fg = match self.1 {
-                Color::Black => "30",
-                Color::Red => "31",
-                Color::Green => "32",
-                Color::Yellow => "33",
-                Color::Blue => "34",
-                Color::Magenta => "35",
-                Color::Cyan => "36",
-                Color::White => "37",
-                Color::BrightBlack => "90",
-                Color::BrightRed => "91",
-                Color::BrightGreen => "92",
-                Color::BrightYellow => "93",
-                Color::BrightBlue => "94",
-                Color::BrightMagenta => "95",
-                Color::BrightCyan => "96",
-                Color::BrightWhite => "97",
-            };
-
-            format!("\u{001B}[{}m{}\u{001B}[0m", fg, self.0)
+            format!("\u{001B}[{}m{}\u{001B}[0m", codes, self.0)
         }
     }
 }
+
+// whether ansi escape codes should be written to stderr
+pub fn stderr_supports_ansi() -> bool {
+    ansi_support(std::io::stderr().is_terminal())
+}
+
+pub fn stdout_supports_ansi() -> bool {
+    ansi_support(std::io::stdout().is_terminal())
+}
+
+// honors https://bixense.com/clicolors and https://no-color.org. forcing colors takes precedence
+// over disabling them
+pub fn ansi_support(is_terminal: bool) -> bool {
+    let var = |key: &str| std::env::var(key).ok().filter(|x| !x.is_empty());
+
+    if var("CLICOLOR_FORCE").is_some_and(|x| x != "0") {
+        return true;
+    }
+
+    if var("NO_COLOR").is_some() {
+        return false;
+    }
+
+    if var("CLICOLOR").is_some_and(|x| x == "0") {
+        return false;
+    }
+
+    if var("TERM").is_some_and(|x| x == "dumb") {
+        return false;
+    }
+
+    is_terminal
+}
@@ -0,0 +1,45 @@
+use crate::constructed_log_message::ConstructedLogFormatArgs;
+use crate::log::IAppender;
+use crate::log_format::LogFormat;
+use crate::log_message::LogMessage;
+use crate::timestamp::TimestampFormat;
+
+pub const DEFAULT_FORMAT_ARGS: ConstructedLogFormatArgs = ConstructedLogFormatArgs {
+    ansi_support: false,
+    show_timestamp: false,
+    timestamp_format: TimestampFormat::RelativeMs,
+    show_counter: false,
+    show_priority: true,
+    show_thread: false,
+    show_foot: false,
+    indent_spans: true,
+};
+
+// writes messages to stderr, above the progress line if one is shown. colors are used, if
+// stderr supports them. if a format is given, it replaces the layout of the format args
+pub struct ConsoleAppender {
+    pub format_args: ConstructedLogFormatArgs,
+    pub format: Option<LogFormat>,
+}
+
+impl Default for ConsoleAppender {
+    fn default() -> Self {
+        let mut format_args = DEFAULT_FORMAT_ARGS;
+        format_args.ansi_support = crate::color_string::stderr_supports_ansi();
+
+        Self {
+            format_args,
+            format: None,
+        }
+    }
+}
+
+impl IAppender for ConsoleAppender {
+    fn print(&mut self, message: &LogMessage) {
+        let message_string = match &self.format {
+            Some(format) => format.fmt(message, self.format_args.ansi_support),
+            None => message.fmt(self.format_args),
+        };
+        crate::progress::eprintln_above(&message_string);
+    }
+}
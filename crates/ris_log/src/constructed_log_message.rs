@@ -1,7 +1,12 @@
+use std::time::Duration;
+use std::time::SystemTime;
+
 use crate::color_string::Color;
 use crate::color_string::ColorString;
 use crate::counter::Counter;
+use crate::field::Field;
 use crate::log_level::LogLevel;
+use crate::timestamp::TimestampFormat;
 
 #[derive(Clone)]
 pub struct ConstructedLogMessage {
@@ -9,45 +14,89 @@ pub struct ConstructedLogMessage {
     pub file: String,
     pub line: u32,
     pub timestamp: Counter,
+    pub elapsed: Duration,
+    pub time: SystemTime,
+    pub thread_id: u64,
+    pub thread_name: Option<String>,
     pub priority: LogLevel,
     pub message: String,
+    pub fields: Vec<Field>,
+    pub span_path: Vec<String>,
 }
 
 #[derive(Debug, Clone, Copy)]
 pub struct ConstructedLogFormatArgs {
     pub ansi_support: bool,
     pub show_timestamp: bool,
+    pub timestamp_format: TimestampFormat,
+    // the sequence number, in addition to the timestamp. allows to restore the order of messages
+    pub show_counter: bool,
     pub show_priority: bool,
+    pub show_thread: bool,
     pub show_foot: bool,
+    pub indent_spans: bool,
 }
 
 impl ConstructedLogMessage {
     pub fn fmt(&self, args: ConstructedLogFormatArgs) -> String {
-        let ConstructedLogFormatArgs { 
+        let ConstructedLogFormatArgs {
             ansi_support,
             show_timestamp,
+            timestamp_format,
+            show_counter,
             show_priority,
+            show_thread,
             show_foot,
+            indent_spans,
         } = args;
 
         let mut result = String::new();
 
+        if indent_spans {
+            for _ in self.span_path.iter() {
+                result.push_str("    ");
+            }
+        }
+
         if show_timestamp {
-            let timestamp = ColorString(&format!("[{}]", self.timestamp.raw()),Color::White).fmt(ansi_support);
+            let timestamp = match timestamp_format {
+                TimestampFormat::Counter => self.timestamp.raw().to_string(),
+                TimestampFormat::RelativeMs => crate::timestamp::fmt_relative_ms(self.elapsed),
+                TimestampFormat::Iso8601 => crate::timestamp::fmt_iso8601(self.time),
+            };
+            let timestamp =
+                ColorString(&format!("[{}]", timestamp), Color::White).fmt(ansi_support);
             result.push_str(&format!("{} ", timestamp));
         }
 
+        if show_counter {
+            let counter = format!("[{}]", self.timestamp.raw());
+            let counter = ColorString(&counter, Color::White).fmt(ansi_support);
+            result.push_str(&format!("{} ", counter));
+        }
+
         if show_priority {
-            let priority_color_string = self.priority.to_color_string();
-            let priority = priority_color_string.fmt(ansi_support);
+            let priority_styled_string = self.priority.to_styled_string();
+            let priority = priority_styled_string.fmt(ansi_support);
             let colon = ColorString(":", Color::White).fmt(ansi_support);
 
             result.push_str(&format!("{}{} ", priority, colon));
         }
 
+        if show_thread {
+            let thread = crate::thread::fmt(self.thread_id, self.thread_name.as_deref());
+            let thread = ColorString(&format!("[{}]", thread), Color::White).fmt(ansi_support);
+            result.push_str(&format!("{} ", thread));
+        }
+
         let message = ColorString(&self.message, Color::BrightWhite).fmt(ansi_support);
         result.push_str(&message);
 
+        for field in self.fields.iter() {
+            let field = ColorString(&field.to_string(), Color::White).fmt(ansi_support);
+            result.push_str(&format!(" {}", field));
+        }
+
         if show_foot {
             let foot = ColorString(
                 &format!("in {} at {}:{}", self.package, self.file, self.line),
@@ -0,0 +1,82 @@
+use crate::constructed_log_message::ConstructedLogMessage;
+use crate::log::IAppender;
+use crate::log_message::LogMessage;
+
+// collapses consecutive repeats of a message into a single "last message repeated n times". a
+// message repeats the previous one, if it was logged at the same place with the same text.
+// everything is passed on to the inner appender
+pub struct DedupAppender {
+    inner: Box<dyn IAppender + Send>,
+    last: Option<ConstructedLogMessage>,
+    repeats: usize,
+}
+
+impl DedupAppender {
+    pub fn new(inner: Box<dyn IAppender + Send>) -> Self {
+        Self {
+            inner,
+            last: None,
+            repeats: 0,
+        }
+    }
+
+    fn print_repeats(&mut self) {
+        if self.repeats == 0 {
+            return;
+        }
+
+        if let Some(last) = self.last.as_ref() {
+            let mut summary = last.clone();
+            summary.message = format!("last message repeated {} times", self.repeats);
+            summary.fields = Vec::new();
+            self.inner.print(&LogMessage::Constructed(summary));
+        }
+
+        self.repeats = 0;
+    }
+}
+
+fn is_repeat(previous: &ConstructedLogMessage, message: &ConstructedLogMessage) -> bool {
+    previous.line == message.line
+        && previous.priority == message.priority
+        && previous.file == message.file
+        && previous.package == message.package
+        && previous.message == message.message
+}
+
+impl IAppender for DedupAppender {
+    fn print(&mut self, message: &LogMessage) {
+        let LogMessage::Constructed(constructed) = message else {
+            self.print_repeats();
+            self.last = None;
+            self.inner.print(message);
+            return;
+        };
+
+        if self
+            .last
+            .as_ref()
+            .is_some_and(|x| is_repeat(x, constructed))
+        {
+            self.repeats += 1;
+            // the summary carries the time of the latest repeat
+            self.last = Some(constructed.clone());
+            return;
+        }
+
+        self.print_repeats();
+        self.last = Some(constructed.clone());
+        self.inner.print(message);
+    }
+
+    fn flush(&mut self) {
+        self.print_repeats();
+        self.inner.flush();
+    }
+}
+
+impl Drop for DedupAppender {
+    fn drop(&mut self) {
+        self.print_repeats();
+    }
+}
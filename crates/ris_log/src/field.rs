@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+use crate::json;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub key: String,
+    pub value: FieldValue,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    Bool(bool),
+    I64(i64),
+    U64(u64),
+    F64(f64),
+    Duration(Duration),
+    Str(String),
+}
+
+impl Field {
+    pub fn new(key: impl Into<String>, value: impl Into<FieldValue>) -> Self {
+        Self {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+}
+
+impl FieldValue {
+    pub fn to_json(&self) -> String {
+        match self {
+            FieldValue::Bool(value) => value.to_string(),
+            FieldValue::I64(value) => value.to_string(),
+            FieldValue::U64(value) => value.to_string(),
+            // json has no representation for nan and infinity
+            FieldValue::F64(value) if !value.is_finite() => String::from("null"),
+            FieldValue::F64(value) => value.to_string(),
+            FieldValue::Duration(value) => value.as_secs_f64().to_string(),
+            FieldValue::Str(value) => json::escape(value),
+        }
+    }
+}
+
+// strings are quoted if necessary, such that `key=value` pairs stay unambiguous
+impl std::fmt::Display for FieldValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldValue::Bool(value) => write!(f, "{}", value),
+            FieldValue::I64(value) => write!(f, "{}", value),
+            FieldValue::U64(value) => write!(f, "{}", value),
+            FieldValue::F64(value) => write!(f, "{}", value),
+            FieldValue::Duration(value) => write!(f, "{:?}", value),
+            FieldValue::Str(value) => {
+                let needs_quotes = value.is_empty()
+                    || value
+                        .chars()
+                        .any(|x| x.is_whitespace() || x == '"' || x == '=');
+
+                if needs_quotes {
+                    write!(f, "{:?}", value)
+                } else {
+                    write!(f, "{}", value)
+                }
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Field {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.key, self.value)
+    }
+}
+
+macro_rules! impl_from {
+    ($variant:ident, $target:ty, $($source:ty),+) => {
+        $(
+            impl From<$source> for FieldValue {
+                fn from(value: $source) -> Self {
+                    FieldValue::$variant(value as $target)
+                }
+            }
+        )+
+    };
+}
+
+impl_from!(I64, i64, i8, i16, i32, i64, isize);
+impl_from!(U64, u64, u8, u16, u32, u64, usize);
+impl_from!(F64, f64, f32, f64);
+
+impl From<bool> for FieldValue {
+    fn from(value: bool) -> Self {
+        FieldValue::Bool(value)
+    }
+}
+
+impl From<Duration> for FieldValue {
+    fn from(value: Duration) -> Self {
+        FieldValue::Duration(value)
+    }
+}
+
+impl From<&str> for FieldValue {
+    fn from(value: &str) -> Self {
+        FieldValue::Str(value.to_string())
+    }
+}
+
+impl From<String> for FieldValue {
+    fn from(value: String) -> Self {
+        FieldValue::Str(value)
+    }
+}
+
+impl From<&String> for FieldValue {
+    fn from(value: &String) -> Self {
+        FieldValue::Str(value.clone())
+    }
+}
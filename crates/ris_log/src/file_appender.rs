@@ -0,0 +1,160 @@
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::constructed_log_message::ConstructedLogFormatArgs;
+use crate::log::IAppender;
+use crate::log_format::LogFormat;
+use crate::log_message::LogMessage;
+use crate::timestamp::TimestampFormat;
+
+pub const DEFAULT_FORMAT_ARGS: ConstructedLogFormatArgs = ConstructedLogFormatArgs {
+    ansi_support: false,
+    show_timestamp: true,
+    timestamp_format: TimestampFormat::Iso8601,
+    show_counter: true,
+    show_priority: true,
+    show_thread: true,
+    show_foot: true,
+    indent_spans: false,
+};
+
+// writes messages to a file. when the file would grow larger than `max_size` bytes, it is rotated:
+// `log` becomes `log.1`, `log.1` becomes `log.2` and so on, until `max_backups` is reached. older
+// backups are deleted. if a format is given, it replaces the layout of the format args
+//
+// each file starts a run with a line like `log run 1234.1 started at <time>`, which the log
+// reader needs to sort messages of several runs
+pub struct FileAppender {
+    path: PathBuf,
+    max_size: u64,
+    max_backups: usize,
+    pub format_args: ConstructedLogFormatArgs,
+    pub format: Option<LogFormat>,
+    file: Option<File>,
+    size: u64,
+    // the index of the run that was last marked in the file
+    run_index: u32,
+}
+
+impl FileAppender {
+    pub fn new(
+        path: impl Into<PathBuf>,
+        max_size: u64,
+        max_backups: usize,
+    ) -> std::io::Result<Self> {
+        let path = path.into();
+        if let Some(directory) = path.parent() {
+            std::fs::create_dir_all(directory)?;
+        }
+
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)?;
+        let size = file.metadata()?.len();
+
+        Ok(Self {
+            path,
+            max_size,
+            max_backups,
+            format_args: DEFAULT_FORMAT_ARGS,
+            format: None,
+            file: Some(file),
+            size,
+            run_index: 0,
+        })
+    }
+
+    fn fmt(&self, message: &LogMessage) -> String {
+        let mut line = match &self.format {
+            Some(format) => format.fmt(message, self.format_args.ansi_support),
+            None => message.fmt(self.format_args),
+        };
+        line.push('\n');
+        line
+    }
+
+    fn backup_path(&self, index: usize) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(format!(".{}", index));
+        PathBuf::from(path)
+    }
+
+    fn rotate(&mut self) -> std::io::Result<()> {
+        // close the file before renaming it
+        self.file = None;
+
+        if self.max_backups == 0 {
+            std::fs::remove_file(&self.path)?;
+        } else {
+            let oldest = self.backup_path(self.max_backups);
+            if oldest.exists() {
+                std::fs::remove_file(oldest)?;
+            }
+
+            for i in (1..self.max_backups).rev() {
+                let from = self.backup_path(i);
+                if from.exists() {
+                    std::fs::rename(from, self.backup_path(i + 1))?;
+                }
+            }
+
+            std::fs::rename(&self.path, self.backup_path(1))?;
+        }
+
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        self.file = Some(file);
+        self.size = 0;
+        self.run_index = 0;
+
+        Ok(())
+    }
+
+    fn write(&mut self, message: &LogMessage) -> std::io::Result<()> {
+        let mut line = self.fmt(message);
+
+        if self.size > 0 && self.size + line.len() as u64 > self.max_size {
+            self.rotate()?;
+        }
+
+        // a rotated file starts with the run as well
+        let run_index = crate::run::index();
+        if run_index != self.run_index {
+            self.run_index = run_index;
+            if let Some(run) = crate::run::current() {
+                line.insert_str(0, &self.fmt(&LogMessage::Plain(run.to_string())));
+            }
+        }
+
+        let Some(file) = self.file.as_mut() else {
+            return Ok(());
+        };
+
+        file.write_all(line.as_bytes())?;
+        self.size += line.len() as u64;
+
+        Ok(())
+    }
+}
+
+impl IAppender for FileAppender {
+    fn print(&mut self, message: &LogMessage) {
+        if let Err(e) = self.write(message) {
+            eprintln!("error while writing to {}: {}", self.path.display(), e);
+        }
+    }
+
+    fn flush(&mut self) {
+        let Some(file) = self.file.as_mut() else {
+            return;
+        };
+
+        if let Err(e) = file.flush() {
+            eprintln!("error while flushing {}: {}", self.path.display(), e);
+        }
+    }
+}
@@ -0,0 +1,359 @@
+// https://www.rfc-editor.org/rfc/rfc8259
+
+pub fn escape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 2);
+    result.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            '\u{0008}' => result.push_str("\\b"),
+            '\u{000C}' => result.push_str("\\f"),
+            c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+
+    result.push('"');
+    result
+}
+
+// numbers are kept as written, such that integers don't lose precision
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            JsonValue::Object(members) => members.iter().find(|(x, _)| x == key).map(|(_, x)| x),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            JsonValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            JsonValue::Number(value) => value.parse().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            JsonValue::Number(value) => value.parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+pub fn parse(value: &str) -> Result<JsonValue, String> {
+    let mut parser = Parser {
+        chars: value.chars().collect(),
+        position: 0,
+    };
+
+    let result = parser.value()?;
+    parser.whitespace();
+    if parser.position < parser.chars.len() {
+        return Err(parser.error("trailing characters"));
+    }
+
+    Ok(result)
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn error(&self, message: &str) -> String {
+        format!("{} at position {}", message, self.position)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek();
+        self.position += 1;
+        c
+    }
+
+    fn whitespace(&mut self) {
+        while self
+            .peek()
+            .is_some_and(|x| matches!(x, ' ' | '\t' | '\n' | '\r'))
+        {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        match self.next() {
+            Some(c) if c == expected => Ok(()),
+            _ => Err(self.error(&format!("expected `{}`", expected))),
+        }
+    }
+
+    fn literal(&mut self, literal: &str, value: JsonValue) -> Result<JsonValue, String> {
+        for expected in literal.chars() {
+            self.expect(expected)?;
+        }
+
+        Ok(value)
+    }
+
+    fn value(&mut self) -> Result<JsonValue, String> {
+        self.whitespace();
+        match self.peek() {
+            Some('n') => self.literal("null", JsonValue::Null),
+            Some('t') => self.literal("true", JsonValue::Bool(true)),
+            Some('f') => self.literal("false", JsonValue::Bool(false)),
+            Some('"') => Ok(JsonValue::String(self.string()?)),
+            Some('[') => self.array(),
+            Some('{') => self.object(),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.number(),
+            _ => Err(self.error("expected a value")),
+        }
+    }
+
+    fn number(&mut self) -> Result<JsonValue, String> {
+        let start = self.position;
+        while self
+            .peek()
+            .is_some_and(|x| x.is_ascii_digit() || matches!(x, '-' | '+' | '.' | 'e' | 'E'))
+        {
+            self.position += 1;
+        }
+
+        let number = self.chars[start..self.position].iter().collect::<String>();
+        if number.parse::<f64>().is_err() {
+            return Err(self.error(&format!("invalid number `{}`", number)));
+        }
+
+        Ok(JsonValue::Number(number))
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+
+        let mut result = String::new();
+        loop {
+            match self.next() {
+                None => return Err(self.error("unterminated string")),
+                Some('"') => return Ok(result),
+                Some('\\') => match self.next() {
+                    Some('"') => result.push('"'),
+                    Some('\\') => result.push('\\'),
+                    Some('/') => result.push('/'),
+                    Some('b') => result.push('\u{0008}'),
+                    Some('f') => result.push('\u{000C}'),
+                    Some('n') => result.push('\n'),
+                    Some('r') => result.push('\r'),
+                    Some('t') => result.push('\t'),
+                    Some('u') => {
+                        let mut code = self.hex()?;
+
+                        // characters outside the basic multilingual plane are written as a
+                        // surrogate pair
+                        if (0xD800..0xDC00).contains(&code) {
+                            self.expect('\\')?;
+                            self.expect('u')?;
+                            let low = self.hex()?;
+                            code = 0x10000
+                                + ((code - 0xD800) << 10)
+                                + (low.wrapping_sub(0xDC00) & 0x3FF);
+                        }
+
+                        result.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+                    }
+                    _ => return Err(self.error("invalid escape sequence")),
+                },
+                Some(c) => result.push(c),
+            }
+        }
+    }
+
+    fn hex(&mut self) -> Result<u32, String> {
+        let mut code = 0;
+        for _ in 0..4 {
+            let digit = self
+                .next()
+                .and_then(|x| x.to_digit(16))
+                .ok_or_else(|| self.error("invalid unicode escape"))?;
+            code = code * 16 + digit;
+        }
+
+        Ok(code)
+    }
+
+    fn array(&mut self) -> Result<JsonValue, String> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+
+        self.whitespace();
+        if self.peek() == Some(']') {
+            self.position += 1;
+            return Ok(JsonValue::Array(items));
+        }
+
+        loop {
+            items.push(self.value()?);
+            self.whitespace();
+            match self.next() {
+                Some(',') => (),
+                Some(']') => return Ok(JsonValue::Array(items)),
+                _ => return Err(self.error("expected `,` or `]`")),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<JsonValue, String> {
+        self.expect('{')?;
+        let mut members = Vec::new();
+
+        self.whitespace();
+        if self.peek() == Some('}') {
+            self.position += 1;
+            return Ok(JsonValue::Object(members));
+        }
+
+        loop {
+            self.whitespace();
+            let key = self.string()?;
+            self.whitespace();
+            self.expect(':')?;
+            let value = self.value()?;
+            members.push((key, value));
+
+            self.whitespace();
+            match self.next() {
+                Some(',') => (),
+                Some('}') => return Ok(JsonValue::Object(members)),
+                _ => return Err(self.error("expected `,` or `}`")),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn string(value: &str) -> JsonValue {
+        JsonValue::String(value.to_string())
+    }
+
+    #[test]
+    fn escape_is_read_back_by_parse() {
+        let values = [
+            "",
+            "plain",
+            "\"quoted\"",
+            "back\\slash",
+            "new\nline\r\ttab",
+            "\u{0000}\u{0008}\u{000C}\u{001F}",
+            "é ü 😀",
+        ];
+
+        for value in values {
+            assert_eq!(parse(&escape(value)), Ok(string(value)), "{:?}", value);
+        }
+    }
+
+    #[test]
+    fn escape_writes_control_characters_as_unicode_escapes() {
+        assert_eq!(escape("a\u{0001}b"), "\"a\\u0001b\"");
+        assert_eq!(escape("\"\\\n"), "\"\\\"\\\\\\n\"");
+    }
+
+    #[test]
+    fn parse_reads_escape_sequences() {
+        assert_eq!(
+            parse(r#""\" \\ \/ \b \f \n \r \t""#),
+            Ok(string("\" \\ / \u{0008} \u{000C} \n \r \t"))
+        );
+        assert_eq!(parse(r#""\u00e9\u00C9""#), Ok(string("éÉ")));
+        // a surrogate pair
+        assert_eq!(parse(r#""\ud83d\ude00""#), Ok(string("😀")));
+    }
+
+    #[test]
+    fn parse_reads_nested_values() {
+        let value = parse(r#" { "a" : [1, -2.5e3, true, null], "b": {}, "c": [] } "#).unwrap();
+
+        assert_eq!(
+            value.get("a"),
+            Some(&JsonValue::Array(vec![
+                JsonValue::Number(String::from("1")),
+                JsonValue::Number(String::from("-2.5e3")),
+                JsonValue::Bool(true),
+                JsonValue::Null,
+            ]))
+        );
+        assert_eq!(value.get("b"), Some(&JsonValue::Object(Vec::new())));
+        assert_eq!(value.get("c"), Some(&JsonValue::Array(Vec::new())));
+        assert_eq!(value.get("d"), None);
+    }
+
+    #[test]
+    fn parse_keeps_integers_precise() {
+        let value = parse("18446744073709551615").unwrap();
+        assert_eq!(value.as_u64(), Some(u64::MAX));
+        assert_eq!(parse("-1").unwrap().as_u64(), None);
+        assert_eq!(parse("0.5").unwrap().as_f64(), Some(0.5));
+    }
+
+    #[test]
+    fn parse_rejects_malformed_input() {
+        let invalid = [
+            "",
+            "nul",
+            "tru",
+            "\"unterminated",
+            r#""\x""#,
+            r#""\u12""#,
+            r#""\u12g4""#,
+            r#""\ud83d""#,
+            "[1, 2",
+            "[1 2]",
+            r#"{"a" 1}"#,
+            r#"{"a": 1,}"#,
+            "{a: 1}",
+            "1.2.3",
+            "-",
+            "{} {}",
+        ];
+
+        for value in invalid {
+            assert!(parse(value).is_err(), "{:?}", value);
+        }
+    }
+
+    #[test]
+    fn parse_reports_the_position_of_an_error() {
+        assert_eq!(
+            parse("[1, x]"),
+            Err(String::from("expected a value at position 4"))
+        );
+    }
+}
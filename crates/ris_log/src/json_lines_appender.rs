@@ -0,0 +1,113 @@
+use std::io::Write;
+use std::path::Path;
+
+use crate::constructed_log_message::ConstructedLogMessage;
+use crate::json;
+use crate::log::IAppender;
+use crate::log_message::LogMessage;
+
+// writes one json object per line, for example:
+// {"package":"a","file":"src/b.rs","line":1,"timestamp":2,"elapsed":0.5,"time":"2025-12-10T14:03:22.123Z","thread_id":1,"thread_name":"main","priority":"Info","message":"c","fields":{"d":3},"span_path":["e"]}
+//
+// like `FileAppender`, the start of each run is marked, i.e.
+// {"message":"log run 1234.1 started at 2025-12-10T14:03:22.123Z"}
+pub struct JsonLinesAppender {
+    writer: Box<dyn Write + Send>,
+    // the index of the run that was last marked
+    run_index: u32,
+}
+
+impl JsonLinesAppender {
+    pub fn new(writer: impl Write + Send + 'static) -> Self {
+        Self {
+            writer: Box::new(writer),
+            run_index: 0,
+        }
+    }
+
+    pub fn create(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let path = path.as_ref();
+        if let Some(directory) = path.parent() {
+            std::fs::create_dir_all(directory)?;
+        }
+
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+
+        Ok(Self::new(file))
+    }
+}
+
+impl IAppender for JsonLinesAppender {
+    fn print(&mut self, message: &LogMessage) {
+        let mut line = String::new();
+
+        let run_index = crate::run::index();
+        if run_index != self.run_index {
+            self.run_index = run_index;
+            if let Some(run) = crate::run::current() {
+                line.push_str(&to_json(&LogMessage::Plain(run.to_string())));
+                line.push('\n');
+            }
+        }
+
+        line.push_str(&to_json(message));
+        line.push('\n');
+
+        if let Err(e) = self.writer.write_all(line.as_bytes()) {
+            eprintln!("error while writing json lines: {}", e);
+        }
+    }
+
+    fn flush(&mut self) {
+        if let Err(e) = self.writer.flush() {
+            eprintln!("error while flushing json lines: {}", e);
+        }
+    }
+}
+
+pub fn to_json(message: &LogMessage) -> String {
+    match message {
+        LogMessage::Constructed(message) => constructed_to_json(message),
+        LogMessage::Plain(message) => format!("{{\"message\":{}}}", json::escape(message)),
+    }
+}
+
+fn constructed_to_json(message: &ConstructedLogMessage) -> String {
+    let fields = message
+        .fields
+        .iter()
+        .map(|x| format!("{}:{}", json::escape(&x.key), x.value.to_json()))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let span_path = message
+        .span_path
+        .iter()
+        .map(|x| json::escape(x))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let thread_name = match message.thread_name.as_deref() {
+        Some(name) => json::escape(name),
+        None => String::from("null"),
+    };
+
+    format!(
+        "{{\"package\":{},\"file\":{},\"line\":{},\"timestamp\":{},\"elapsed\":{},\"time\":{},\"thread_id\":{},\"thread_name\":{},\"priority\":{},\"message\":{},\"fields\":{{{}}},\"span_path\":[{}]}}",
+        json::escape(&message.package),
+        json::escape(&message.file),
+        message.line,
+        message.timestamp.raw(),
+        message.elapsed.as_secs_f64(),
+        json::escape(&crate::timestamp::fmt_iso8601(message.time)),
+        message.thread_id,
+        thread_name,
+        json::escape(message.priority.to_styled_string().0),
+        json::escape(&message.message),
+        fields,
+        span_path,
+    )
+}
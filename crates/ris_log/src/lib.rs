@@ -1,6 +1,29 @@
+// allows the macros to be used inside this crate
+extern crate self as ris_log;
+
+pub mod capture_appender;
 pub mod color_string;
+pub mod console_appender;
 pub mod constructed_log_message;
 pub mod counter;
+pub mod dedup_appender;
+pub mod field;
+pub mod file_appender;
+pub mod json;
+pub mod json_lines_appender;
 pub mod log;
+#[cfg(feature = "log")]
+pub mod log_facade;
+pub mod log_filter;
+pub mod log_format;
 pub mod log_level;
 pub mod log_message;
+pub mod log_reader;
+pub mod panic_hook;
+pub mod progress;
+pub mod queue;
+pub mod rate_limit;
+pub mod run;
+pub mod span;
+pub mod thread;
+pub mod timestamp;
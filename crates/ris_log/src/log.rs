@@ -1,42 +1,185 @@
 #[cfg(feature = "logging_enabled")]
 use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
     sync::{
-        mpsc::{channel, Receiver, Sender},
-        Mutex,
+        atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicUsize, Ordering},
+        mpsc::Sender,
+        Arc, Mutex,
     },
     thread::JoinHandle,
 };
 
 use crate::counter::Counter;
+use crate::log_filter::LogFilter;
 use crate::log_level::LogLevel;
 use crate::log_message::LogMessage;
+use crate::queue::OverflowPolicy;
+#[cfg(feature = "logging_enabled")]
+use crate::queue::{Queue, QueueItem};
 
 pub trait IAppender {
     fn print(&mut self, message: &LogMessage);
+
+    // writes everything that was buffered so far
+    fn flush(&mut self) {}
+}
+
+// appenders passed to `init` get the ids 0, 1, 2 and so on, in the order they were passed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AppenderId(pub usize);
+
+#[cfg(feature = "logging_enabled")]
+enum Command {
+    Log(LogMessage),
+    AddAppender(AppenderId, Box<dyn IAppender + Send>),
+    RemoveAppender(AppenderId),
+    // flushes all appenders and then acknowledges through the sender
+    Flush(Sender<()>),
+}
+
+#[cfg(feature = "logging_enabled")]
+type Appenders = Vec<(AppenderId, Box<dyn IAppender + Send>)>;
+
+#[cfg(feature = "logging_enabled")]
+impl QueueItem for Command {
+    fn priority(&self) -> Option<LogLevel> {
+        match self {
+            Command::Log(LogMessage::Constructed(message)) => Some(message.priority),
+            // plain messages have no level. they are only dropped when all messages may be
+            Command::Log(LogMessage::Plain(_)) => Some(LogLevel::None),
+            Command::AddAppender(..) | Command::RemoveAppender(_) | Command::Flush(_) => None,
+        }
+    }
 }
 
 #[cfg(feature = "logging_enabled")]
 pub static LOG: Mutex<Option<Logger>> = Mutex::new(None);
 
+// the log macros only read these, such that they don't need to lock `LOG` in the common case.
+// they are updated whenever the logger changes
+#[cfg(feature = "logging_enabled")]
+static MAX_LEVEL: AtomicUsize = AtomicUsize::new(LogLevel::None as usize);
+#[cfg(feature = "logging_enabled")]
+static HAS_DIRECTIVES: AtomicBool = AtomicBool::new(false);
+#[cfg(feature = "logging_enabled")]
+static COUNTER: AtomicU32 = AtomicU32::new(0);
+// invalidates what each thread caches
+#[cfg(feature = "logging_enabled")]
+static GENERATION: AtomicU64 = AtomicU64::new(1);
+
+// where `forward_to_appenders` sends messages to
+#[cfg(feature = "logging_enabled")]
+#[derive(Clone)]
+enum Target {
+    None,
+    Queue(Arc<Queue<Command>>),
+    Synchronous(Arc<Mutex<Appenders>>),
+}
+
+// each thread caches what it needs from the logger, such that `LOG` is only locked after the
+// logger changed
+#[cfg(feature = "logging_enabled")]
+struct ThreadCache {
+    generation: u64,
+    target: Target,
+    filter: Option<LogFilter>,
+    // the levels resolved by the filter, by package and file
+    levels: HashMap<String, HashMap<String, LogLevel>>,
+}
+
+#[cfg(feature = "logging_enabled")]
+impl ThreadCache {
+    fn level(&mut self, package: &str, file: &str) -> LogLevel {
+        let Some(filter) = &self.filter else {
+            return LogLevel::None;
+        };
+
+        if let Some(level) = self.levels.get(package).and_then(|x| x.get(file)) {
+            return *level;
+        }
+
+        let level = filter.level(package, file);
+        self.levels
+            .entry(package.to_string())
+            .or_default()
+            .insert(file.to_string(), level);
+        level
+    }
+}
+
+#[cfg(feature = "logging_enabled")]
+thread_local! {
+    static THREAD_CACHE: RefCell<Option<ThreadCache>> = const { RefCell::new(None) };
+    // set while this thread holds the appenders, i.e. on the log thread or while printing
+    // synchronously. logging from here would wait for the appenders, that this thread holds
+    static HOLDS_APPENDERS: Cell<bool> = const { Cell::new(false) };
+}
+
+// resets `HOLDS_APPENDERS`, even if an appender panics
+#[cfg(feature = "logging_enabled")]
+struct HoldsAppendersGuard(bool);
+
+#[cfg(feature = "logging_enabled")]
+impl HoldsAppendersGuard {
+    fn new() -> Self {
+        let previous = HOLDS_APPENDERS.with(|x| x.replace(true));
+        Self(previous)
+    }
+}
+
+#[cfg(feature = "logging_enabled")]
+impl Drop for HoldsAppendersGuard {
+    fn drop(&mut self) {
+        HOLDS_APPENDERS.with(|x| x.set(self.0));
+    }
+}
+
+// whether messages logged on the current thread could never be written, because the thread
+// itself is the one that writes them
+pub fn holds_appenders() -> bool {
+    #[cfg(feature = "logging_enabled")]
+    {
+        HOLDS_APPENDERS.try_with(|x| x.get()).unwrap_or(false)
+    }
+
+    #[cfg(not(feature = "logging_enabled"))]
+    {
+        false
+    }
+}
+
+pub const LOG_THREAD_NAME: &str = "ris_log";
+
 pub struct LogGuard;
 
 #[cfg(feature = "logging_enabled")]
 impl Drop for LogGuard {
     fn drop(&mut self) {
-        match LOG.lock() {
-            Err(e) => eprintln!("error while dropping log: {}", e),
+        // the logger joins the log thread when it's dropped. `LOG` must not be locked by then,
+        // such that messages logged meanwhile, i.e. by a panicking appender, don't wait for it
+        let logger = match LOG.lock() {
+            Err(e) => {
+                eprintln!("error while dropping log: {}", e);
+                return;
+            }
             Ok(mut log) => {
-                *log = None;
+                publish(None);
+                log.take()
             }
-        }
+        };
+
+        drop(logger);
     }
 }
 
 #[cfg(feature = "logging_enabled")]
 pub struct Logger {
-    counter: Counter,
-    log_level: LogLevel,
-    sender: Option<Sender<LogMessage>>,
+    filter: LogFilter,
+    next_appender_id: usize,
+    queue: Option<Arc<Queue<Command>>>,
+    appenders: Arc<Mutex<Appenders>>,
+    synchronous: bool,
     thread_handle: Option<JoinHandle<()>>,
 }
 
@@ -46,7 +189,9 @@ pub struct Logger;
 #[cfg(feature = "logging_enabled")]
 impl Drop for Logger {
     fn drop(&mut self) {
-        self.sender.take();
+        if let Some(queue) = self.queue.take() {
+            queue.close();
+        }
 
         if let Some(thread_handle) = self.thread_handle.take() {
             if thread_handle.join().is_err() {
@@ -56,29 +201,64 @@ impl Drop for Logger {
     }
 }
 
-pub fn init(log_level: LogLevel, appenders: Vec<Box<dyn IAppender + Send>>) -> LogGuard {
+// the filter may be overwritten by the environment variable `RIS_LOG`
+pub fn init(filter: impl Into<LogFilter>, appenders: Vec<Box<dyn IAppender + Send>>) -> LogGuard {
     #[cfg(feature = "logging_enabled")]
     {
-        if matches!(log_level, LogLevel::None) || appenders.is_empty() {
-            return LogGuard;
-        }
+        crate::timestamp::start();
+
+        let filter = match LogFilter::from_env() {
+            Some(Ok(filter)) => filter,
+            Some(Err(e)) => {
+                eprintln!("error while parsing {}: {}", crate::log_filter::ENV_VAR, e);
+                filter.into()
+            }
+            None => filter.into(),
+        };
 
-        let (sender, receiver) = channel();
-        let sender = Some(sender);
-        let thread_handle = Some(std::thread::spawn(|| {
-            log_thread(receiver, appenders);
-        }));
+        // the logger is created even if nothing can be logged yet, since the filter and the
+        // appenders may be changed later
+        let next_appender_id = appenders.len();
+        let appenders = appenders
+            .into_iter()
+            .enumerate()
+            .map(|(i, appender)| (AppenderId(i), appender))
+            .collect();
+        let appenders = Arc::new(Mutex::new(appenders));
+
+        let queue = Arc::new(Queue::new(
+            crate::queue::DEFAULT_CAPACITY,
+            OverflowPolicy::Block,
+        ));
+        let receiver = queue.clone();
+        let queue = Some(queue);
+        let thread_appenders = appenders.clone();
+        let thread_handle = std::thread::Builder::new()
+            .name(String::from(LOG_THREAD_NAME))
+            .spawn(|| log_thread(receiver, thread_appenders));
+        let thread_handle = match thread_handle {
+            Ok(thread_handle) => Some(thread_handle),
+            Err(e) => {
+                eprintln!("error while spawning log thread: {}", e);
+                None
+            }
+        };
 
         let logger = Logger {
-            counter: Counter::default(),
-            log_level,
-            sender,
+            filter,
+            next_appender_id,
+            queue,
+            appenders,
+            synchronous: false,
             thread_handle,
         };
 
         match LOG.lock() {
             Ok(mut log) => {
+                COUNTER.store(0, Ordering::Relaxed);
+                crate::run::start();
                 *log = Some(logger);
+                publish(log.as_ref());
             }
             Err(e) => {
                 eprintln!("error while initializing log: {}", e);
@@ -90,60 +270,356 @@ pub fn init(log_level: LogLevel, appenders: Vec<Box<dyn IAppender + Send>>) -> L
 
     #[cfg(not(feature = "logging_enabled"))]
     {
-        let _ = log_level;
+        let _ = filter;
         let _ = appenders;
 
         LogGuard
     }
 }
 
+// must be called while `LOG` is locked, whenever the filter or the target of messages changes
+#[cfg(feature = "logging_enabled")]
+fn publish(logger: Option<&Logger>) {
+    let (max_level, has_directives) = match logger {
+        Some(logger) => (
+            logger.filter.max_verbosity(),
+            !logger.filter.directives.is_empty(),
+        ),
+        None => (LogLevel::None, false),
+    };
+
+    MAX_LEVEL.store(max_level as usize, Ordering::Relaxed);
+    HAS_DIRECTIVES.store(has_directives, Ordering::Relaxed);
+    GENERATION.fetch_add(1, Ordering::Relaxed);
+}
+
+#[cfg(feature = "logging_enabled")]
+fn current_target() -> Target {
+    match LOG.lock() {
+        Err(e) => {
+            eprintln!("error while getting log target: {}", e);
+            Target::None
+        }
+        Ok(log) => target_of(log.as_ref()),
+    }
+}
+
+#[cfg(feature = "logging_enabled")]
+fn target_of(logger: Option<&Logger>) -> Target {
+    match logger {
+        Some(logger) if logger.synchronous => Target::Synchronous(logger.appenders.clone()),
+        Some(logger) => match &logger.queue {
+            Some(queue) => Target::Queue(queue.clone()),
+            None => Target::None,
+        },
+        None => Target::None,
+    }
+}
+
+// runs `f` with the cache of the current thread, after refreshing it if the logger changed.
+// returns `None` while the thread is shutting down
+#[cfg(feature = "logging_enabled")]
+fn with_thread_cache<T>(f: impl FnOnce(&mut ThreadCache) -> T) -> Option<T> {
+    // the generation is loaded before the logger, such that a change in between leads to
+    // another refresh
+    let generation = GENERATION.load(Ordering::Relaxed);
+
+    THREAD_CACHE
+        .try_with(|x| {
+            let mut cache = x.borrow_mut();
+            let is_outdated = cache.as_ref().is_none_or(|x| x.generation != generation);
+
+            if is_outdated {
+                let (target, filter) = match LOG.lock() {
+                    Err(e) => {
+                        eprintln!("error while refreshing log cache: {}", e);
+                        (Target::None, None)
+                    }
+                    Ok(log) => (
+                        target_of(log.as_ref()),
+                        log.as_ref().map(|x| x.filter.clone()),
+                    ),
+                };
+
+                *cache = Some(ThreadCache {
+                    generation,
+                    target,
+                    filter,
+                    levels: HashMap::new(),
+                });
+            }
+
+            cache.as_mut().map(f)
+        })
+        .ok()
+        .flatten()
+}
+
+// closes the queue when the log thread exits, even if an appender panics. otherwise producers
+// would wait forever for room in a queue, that is never popped again. pending flushes are
+// released by dropping their senders
+#[cfg(feature = "logging_enabled")]
+struct CloseQueueGuard(Arc<Queue<Command>>);
+
 #[cfg(feature = "logging_enabled")]
-fn log_thread(receiver: Receiver<LogMessage>, mut appenders: Vec<Box<dyn IAppender + Send>>) {
-    for log_message in receiver.iter() {
-        for appender in appenders.iter_mut() {
-            appender.print(&log_message);
+impl Drop for CloseQueueGuard {
+    fn drop(&mut self) {
+        self.0.close();
+        self.0.clear();
+    }
+}
+
+#[cfg(feature = "logging_enabled")]
+fn log_thread(queue: Arc<Queue<Command>>, appenders: Arc<Mutex<Appenders>>) {
+    let _holds_appenders = HoldsAppendersGuard::new();
+    let _close_queue = CloseQueueGuard(queue.clone());
+
+    while let Some(command) = queue.pop() {
+        let mut appenders = match appenders.lock() {
+            Ok(appenders) => appenders,
+            Err(e) => {
+                eprintln!("error while locking appenders: {}", e);
+                continue;
+            }
+        };
+
+        match command {
+            Command::Log(log_message) => print(&mut appenders, &log_message),
+            Command::AddAppender(id, appender) => appenders.push((id, appender)),
+            Command::RemoveAppender(id) => appenders.retain(|(x, _)| *x != id),
+            Command::Flush(sender) => {
+                flush_appenders(&mut appenders);
+                let _ = sender.send(());
+            }
         }
     }
 
-    //let final_log_message = LogMessage::Plain(String::from("log thread ended"));
+    let dropped = queue.dropped();
+    if dropped > 0 {
+        let final_log_message = LogMessage::Plain(format!(
+            "{} log messages were dropped, because the log queue was full",
+            dropped,
+        ));
+
+        match appenders.lock() {
+            Ok(mut appenders) => {
+                print(&mut appenders, &final_log_message);
+                flush_appenders(&mut appenders);
+            }
+            Err(e) => eprintln!("error while locking appenders: {}", e),
+        }
+    }
+}
+
+#[cfg(feature = "logging_enabled")]
+fn print(appenders: &mut Appenders, log_message: &LogMessage) {
+    for (_, appender) in appenders.iter_mut() {
+        appender.print(log_message);
+    }
+}
 
-    //for appender in appenders.iter_mut() {
-    //    appender.print(&final_log_message);
-    //}
+#[cfg(feature = "logging_enabled")]
+fn flush_appenders(appenders: &mut Appenders) {
+    for (_, appender) in appenders.iter_mut() {
+        appender.flush();
+    }
 }
 
+// the most verbose level that any message may be logged at
 pub fn log_level() -> LogLevel {
     #[cfg(feature = "logging_enabled")]
     {
+        LogLevel::from(MAX_LEVEL.load(Ordering::Relaxed))
+    }
+
+    #[cfg(not(feature = "logging_enabled"))]
+    {
+        LogLevel::None
+    }
+}
+
+// the level of messages logged in the given package and file
+pub fn log_level_for(package: &str, file: &str) -> LogLevel {
+    #[cfg(feature = "logging_enabled")]
+    {
+        // without directives, every message has the same level
+        if !HAS_DIRECTIVES.load(Ordering::Relaxed) {
+            return log_level();
+        }
+
+        if let Some(level) = with_thread_cache(|x| x.level(package, file)) {
+            return level;
+        }
+
         match LOG.lock() {
             Err(e) => eprintln!("error while getting log_level: {}", e),
             Ok(log) => {
                 if let Some(logger) = &*log {
-                    return logger.log_level;
+                    return logger.filter.level(package, file);
                 }
             }
         }
     }
 
+    #[cfg(not(feature = "logging_enabled"))]
+    {
+        let _ = package;
+        let _ = file;
+    }
+
     LogLevel::None
 }
 
-pub fn get_timestamp() -> Counter {
+pub fn filter() -> Option<LogFilter> {
     #[cfg(feature = "logging_enabled")]
     {
         match LOG.lock() {
-            Err(e) => eprintln!("error while getting log_level: {}", e),
+            Err(e) => eprintln!("error while getting filter: {}", e),
+            Ok(log) => {
+                if let Some(logger) = &*log {
+                    return Some(logger.filter.clone());
+                }
+            }
+        }
+    }
+
+    None
+}
+
+pub fn set_filter(filter: impl Into<LogFilter>) {
+    #[cfg(feature = "logging_enabled")]
+    {
+        match LOG.lock() {
+            Err(e) => eprintln!("error while setting filter: {}", e),
+            Ok(mut log) => {
+                if let Some(logger) = log.as_mut() {
+                    logger.filter = filter.into();
+                }
+                publish(log.as_ref());
+            }
+        }
+    }
+
+    #[cfg(not(feature = "logging_enabled"))]
+    {
+        let _ = filter;
+    }
+}
+
+// changes the level of all messages that don't match a more specific directive
+pub fn set_log_level(log_level: LogLevel) {
+    #[cfg(feature = "logging_enabled")]
+    {
+        match LOG.lock() {
+            Err(e) => eprintln!("error while setting log_level: {}", e),
+            Ok(mut log) => {
+                if let Some(logger) = log.as_mut() {
+                    logger.filter.default = log_level;
+                }
+                publish(log.as_ref());
+            }
+        }
+    }
+
+    #[cfg(not(feature = "logging_enabled"))]
+    {
+        let _ = log_level;
+    }
+}
+
+// replaces the filter until the returned guard is dropped
+#[must_use]
+pub fn scoped_filter(filter: impl Into<LogFilter>) -> ScopedFilterGuard {
+    let previous = self::filter();
+    set_filter(filter);
+    ScopedFilterGuard { previous }
+}
+
+pub struct ScopedFilterGuard {
+    previous: Option<LogFilter>,
+}
+
+impl Drop for ScopedFilterGuard {
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous.take() {
+            set_filter(previous);
+        }
+    }
+}
+
+// returns `None` if the log is not initialized
+pub fn add_appender(appender: Box<dyn IAppender + Send>) -> Option<AppenderId> {
+    #[cfg(feature = "logging_enabled")]
+    {
+        match LOG.lock() {
+            Err(e) => eprintln!("error while adding appender: {}", e),
             Ok(mut log) => {
                 if let Some(logger) = log.as_mut() {
-                    let previous = logger.counter;
-                    logger.counter.increase();
-                    return previous;
+                    let id = AppenderId(logger.next_appender_id);
+                    logger.next_appender_id += 1;
+
+                    if logger.synchronous {
+                        match logger.appenders.lock() {
+                            Ok(mut appenders) => appenders.push((id, appender)),
+                            Err(e) => eprintln!("error while adding appender: {}", e),
+                        }
+                        return Some(id);
+                    }
+
+                    if let Some(queue) = &logger.queue {
+                        queue.push(Command::AddAppender(id, appender));
+                        return Some(id);
+                    }
                 }
             }
         }
     }
 
-    Counter::default()
+    #[cfg(not(feature = "logging_enabled"))]
+    {
+        let _ = appender;
+    }
+
+    None
+}
+
+pub fn remove_appender(id: AppenderId) {
+    #[cfg(feature = "logging_enabled")]
+    {
+        match LOG.lock() {
+            Err(e) => eprintln!("error while removing appender: {}", e),
+            Ok(log) => {
+                if let Some(logger) = &*log {
+                    if logger.synchronous {
+                        match logger.appenders.lock() {
+                            Ok(mut appenders) => appenders.retain(|(x, _)| *x != id),
+                            Err(e) => eprintln!("error while removing appender: {}", e),
+                        }
+                    } else if let Some(queue) = &logger.queue {
+                        queue.push(Command::RemoveAppender(id));
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(not(feature = "logging_enabled"))]
+    {
+        let _ = id;
+    }
+}
+
+pub fn get_timestamp() -> Counter {
+    #[cfg(feature = "logging_enabled")]
+    {
+        // wraps around on overflow, just like `Counter::increase`
+        Counter::from_raw(COUNTER.fetch_add(1, Ordering::Relaxed))
+    }
+
+    #[cfg(not(feature = "logging_enabled"))]
+    {
+        Counter::default()
+    }
 }
 
 pub fn can_log(log_level: LogLevel, message_priority: LogLevel) -> bool {
@@ -153,27 +629,154 @@ pub fn can_log(log_level: LogLevel, message_priority: LogLevel) -> bool {
     }
 }
 
+// whether a message of the given priority, logged in the given package and file, passes the
+// filter. messages above the most verbose level are rejected without locking
+pub fn enabled(message_priority: LogLevel, package: &str, file: &str) -> bool {
+    if !crate::log_level::static_enabled(message_priority) {
+        return false;
+    }
+
+    if !can_log(log_level(), message_priority) {
+        return false;
+    }
+
+    can_log(log_level_for(package, file), message_priority)
+}
+
 pub fn forward_to_appenders(log_message: LogMessage) {
     #[cfg(feature = "logging_enabled")]
     {
+        // the cache is not available while the thread is shutting down
+        let target = with_thread_cache(|x| x.target.clone()).unwrap_or_else(current_target);
+
+        match target {
+            Target::None => (),
+            Target::Queue(queue) => queue.push(Command::Log(log_message)),
+            Target::Synchronous(appenders) => match appenders.lock() {
+                Ok(mut appenders) => {
+                    let _holds_appenders = HoldsAppendersGuard::new();
+                    print(&mut appenders, &log_message);
+                }
+                Err(e) => eprintln!("error while forwarding to appenders: {}", e),
+            },
+        }
+    }
+
+    #[cfg(not(feature = "logging_enabled"))]
+    {
+        let _ = log_message;
+    }
+}
+
+// blocks until every message logged so far is written and all appenders are flushed
+pub fn flush() {
+    #[cfg(feature = "logging_enabled")]
+    {
+        let queue = match LOG.lock() {
+            Err(e) => {
+                eprintln!("error while flushing log: {}", e);
+                return;
+            }
+            Ok(log) => log.as_ref().and_then(|x| x.queue.clone()),
+        };
+
+        // the appenders are flushed by the log thread, even in synchronous mode. this way,
+        // messages that were queued before switching to synchronous mode are written as well
+        if let Some(queue) = queue {
+            let (sender, receiver) = std::sync::mpsc::channel();
+            queue.push(Command::Flush(sender));
+
+            // if the queue is closed, the sender is dropped and this returns immediately
+            let _ = receiver.recv();
+        }
+    }
+}
+
+// in synchronous mode, messages are written on the thread that logs them, before the log macro
+// returns. this is slower, but keeps the log in order with anything else written to stdout or
+// stderr
+pub fn set_synchronous(synchronous: bool) {
+    #[cfg(feature = "logging_enabled")]
+    {
+        // write queued messages first, such that they stay in order
+        if synchronous {
+            flush();
+        }
+
         match LOG.lock() {
-            Err(e) => eprintln!("error while forwarding to appenders: {}", e),
+            Err(e) => eprintln!("error while setting synchronous: {}", e),
             Ok(mut log) => {
-                if let Some(logger) = &mut *log {
-                    if let Some(sender) = &mut logger.sender {
-                        let _ = sender.send(log_message);
-                    }
+                if let Some(logger) = log.as_mut() {
+                    logger.synchronous = synchronous;
                 }
+                publish(log.as_ref());
             }
         }
     }
 
     #[cfg(not(feature = "logging_enabled"))]
     {
-        let _ = log_message;
+        let _ = synchronous;
+    }
+}
+
+// the maximum number of messages that wait for the log thread. what happens to messages beyond
+// that is decided by the overflow policy
+pub fn set_queue_capacity(capacity: usize) {
+    #[cfg(feature = "logging_enabled")]
+    {
+        match LOG.lock() {
+            Err(e) => eprintln!("error while setting queue capacity: {}", e),
+            Ok(log) => {
+                if let Some(queue) = log.as_ref().and_then(|x| x.queue.as_ref()) {
+                    queue.set_capacity(capacity);
+                }
+            }
+        }
+    }
+
+    #[cfg(not(feature = "logging_enabled"))]
+    {
+        let _ = capacity;
     }
 }
 
+pub fn set_overflow_policy(policy: OverflowPolicy) {
+    #[cfg(feature = "logging_enabled")]
+    {
+        match LOG.lock() {
+            Err(e) => eprintln!("error while setting overflow policy: {}", e),
+            Ok(log) => {
+                if let Some(queue) = log.as_ref().and_then(|x| x.queue.as_ref()) {
+                    queue.set_policy(policy);
+                }
+            }
+        }
+    }
+
+    #[cfg(not(feature = "logging_enabled"))]
+    {
+        let _ = policy;
+    }
+}
+
+// the number of messages that were dropped so far
+pub fn dropped_messages() -> usize {
+    #[cfg(feature = "logging_enabled")]
+    {
+        match LOG.lock() {
+            Err(e) => eprintln!("error while getting dropped messages: {}", e),
+            Ok(log) => {
+                if let Some(queue) = log.as_ref().and_then(|x| x.queue.as_ref()) {
+                    return queue.dropped();
+                }
+            }
+        }
+    }
+
+    0
+}
+
 #[macro_export]
 macro_rules! trace {
     ($($arg:tt)*) => {
@@ -216,26 +819,42 @@ macro_rules! fatal {
     };
 }
 
+// key-value fields may be attached before the message, separated by a semicolon:
+//
+//     ris_log::info!(day = 5, part = 2; "solved in {:?}", elapsed);
 #[cfg(feature = "logging_enabled")]
 #[macro_export]
 macro_rules! log {
-    ($priority:expr, $($arg:tt)*) => {{
-        let log_level = ris_log::log::log_level();
-        if (ris_log::log::can_log(log_level, $priority)) {
+    (@construct $priority:expr, [$($key:ident = $value:expr),*], $($arg:tt)*) => {{
+        if ris_log::log_level::static_enabled($priority)
+            && ris_log::log::enabled($priority, env!("CARGO_PKG_NAME"), file!())
+        {
             let package = String::from(env!("CARGO_PKG_NAME"));
             let file = String::from(file!());
             let line = line!();
             let timestamp = ris_log::log::get_timestamp();
+            let elapsed = ris_log::timestamp::elapsed();
+            let time = std::time::SystemTime::now();
+            let thread_id = ris_log::thread::id();
+            let thread_name = ris_log::thread::name();
             let priority = $priority;
             let message = format!($($arg)*);
+            let fields = vec![$(ris_log::field::Field::new(stringify!($key), $value)),*];
+            let span_path = ris_log::span::current_path();
 
             let constructed_log = ris_log::constructed_log_message::ConstructedLogMessage {
                 package,
                 file,
                 line,
                 timestamp,
+                elapsed,
+                time,
+                thread_id,
+                thread_name,
                 priority,
                 message,
+                fields,
+                span_path,
             };
 
             let message = ris_log::log_message::LogMessage::Constructed(constructed_log);
@@ -243,11 +862,22 @@ macro_rules! log {
             ris_log::log::forward_to_appenders(message);
         }
     }};
+    ($priority:expr, $($key:ident = $value:expr),+ ; $($arg:tt)*) => {
+        ris_log::log!(@construct $priority, [$($key = $value),+], $($arg)*)
+    };
+    ($priority:expr, $($arg:tt)*) => {
+        ris_log::log!(@construct $priority, [], $($arg)*)
+    };
 }
 
 #[cfg(not(feature = "logging_enabled"))]
 #[macro_export]
 macro_rules! log {
+    ($priority:expr, $($key:ident = $value:expr),+ ; $($arg:expr),* $(,)?) => {{
+        let _ = $priority;
+        $(let _ = &$value;)+
+        $(let _ = &$arg;)*
+    }};
     ($priority:expr, $($arg:expr),* $(,)?) => {{
         let _ = $priority;
         $(let _ = &$arg;)*
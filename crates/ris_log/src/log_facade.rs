@@ -0,0 +1,149 @@
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::time::SystemTime;
+
+use crate::constructed_log_message::ConstructedLogFormatArgs;
+use crate::constructed_log_message::ConstructedLogMessage;
+use crate::log::IAppender;
+use crate::log_level::LogLevel;
+use crate::log_message::LogMessage;
+use crate::timestamp::TimestampFormat;
+
+static LOG_FACADE: LogFacade = LogFacade;
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+const MESSAGE_FORMAT_ARGS: ConstructedLogFormatArgs = ConstructedLogFormatArgs {
+    ansi_support: false,
+    show_timestamp: false,
+    timestamp_format: TimestampFormat::Counter,
+    show_counter: false,
+    show_priority: false,
+    show_thread: false,
+    show_foot: false,
+    indent_spans: false,
+};
+
+pub fn from_log_level(level: log::Level) -> LogLevel {
+    match level {
+        log::Level::Error => LogLevel::Error,
+        log::Level::Warn => LogLevel::Warning,
+        log::Level::Info => LogLevel::Info,
+        log::Level::Debug => LogLevel::Debug,
+        log::Level::Trace => LogLevel::Trace,
+    }
+}
+
+// `log` has no fatal level, and `None` is never logged
+pub fn to_log_level(level: LogLevel) -> Option<log::Level> {
+    match level {
+        LogLevel::Trace => Some(log::Level::Trace),
+        LogLevel::Debug => Some(log::Level::Debug),
+        LogLevel::Info => Some(log::Level::Info),
+        LogLevel::Warning => Some(log::Level::Warn),
+        LogLevel::Error | LogLevel::Fatal => Some(log::Level::Error),
+        LogLevel::None => None,
+    }
+}
+
+// forwards records of the `log` crate to the appenders of ris_log. records are filtered like
+// messages of ris_log, using the crate and file they were logged in
+pub struct LogFacade;
+
+impl LogFacade {
+    fn package<'a>(metadata: &log::Metadata<'a>) -> &'a str {
+        let target = metadata.target();
+        target.split("::").next().unwrap_or(target)
+    }
+}
+
+impl log::Log for LogFacade {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        let package = Self::package(metadata);
+        crate::log::enabled(from_log_level(metadata.level()), package, "")
+    }
+
+    fn log(&self, record: &log::Record) {
+        let package = Self::package(record.metadata());
+        let file = record.file().unwrap_or("");
+        let priority = from_log_level(record.level());
+
+        if !crate::log::enabled(priority, package, file) {
+            return;
+        }
+
+        let constructed_log = ConstructedLogMessage {
+            package: package.to_string(),
+            file: file.to_string(),
+            line: record.line().unwrap_or(0),
+            timestamp: crate::log::get_timestamp(),
+            elapsed: crate::timestamp::elapsed(),
+            time: SystemTime::now(),
+            thread_id: crate::thread::id(),
+            thread_name: crate::thread::name(),
+            priority,
+            message: record.args().to_string(),
+            fields: Vec::new(),
+            span_path: crate::span::current_path(),
+        };
+
+        crate::log::forward_to_appenders(LogMessage::Constructed(constructed_log));
+    }
+
+    fn flush(&self) {
+        crate::log::flush();
+    }
+}
+
+// makes ris_log the logger of the `log` crate. fails if another logger was installed already
+pub fn install() -> Result<(), log::SetLoggerError> {
+    log::set_logger(&LOG_FACADE)?;
+
+    // filtering is done by ris_log, since its filter may change at runtime
+    log::set_max_level(log::LevelFilter::Trace);
+    INSTALLED.store(true, Ordering::Relaxed);
+
+    Ok(())
+}
+
+// passes messages of ris_log on to the logger of the `log` crate. if ris_log itself is that
+// logger, messages are dropped, since they would be forwarded back and forth forever
+pub struct LogFacadeAppender;
+
+impl IAppender for LogFacadeAppender {
+    fn print(&mut self, message: &LogMessage) {
+        if INSTALLED.load(Ordering::Relaxed) {
+            return;
+        }
+
+        match message {
+            LogMessage::Constructed(message) => {
+                let Some(level) = to_log_level(message.priority) else {
+                    return;
+                };
+
+                let text = message.fmt(MESSAGE_FORMAT_ARGS);
+                log::logger().log(
+                    &log::Record::builder()
+                        .args(format_args!("{}", text))
+                        .level(level)
+                        .target(&message.package)
+                        .file(Some(&message.file))
+                        .line(Some(message.line))
+                        .build(),
+                );
+            }
+            LogMessage::Plain(message) => {
+                log::logger().log(
+                    &log::Record::builder()
+                        .args(format_args!("{}", message))
+                        .level(log::Level::Info)
+                        .build(),
+                );
+            }
+        }
+    }
+
+    fn flush(&mut self) {
+        log::logger().flush();
+    }
+}
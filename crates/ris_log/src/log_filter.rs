@@ -0,0 +1,124 @@
+use crate::log_level::LogLevel;
+
+pub const ENV_VAR: &str = "RIS_LOG";
+
+// a filter consists of comma separated directives. a directive is either a level, which applies
+// to all messages, or `<target>=<level>`, which applies to all messages of the target and its
+// submodules. the most specific directive wins. example:
+//
+//     advent_of_code_2025::aoc_2025::day_10=warning,ris_error=error,info
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFilter {
+    pub default: LogLevel,
+    pub directives: Vec<Directive>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Directive {
+    pub target: String,
+    pub level: LogLevel,
+}
+
+impl From<LogLevel> for LogFilter {
+    fn from(value: LogLevel) -> Self {
+        Self {
+            default: value,
+            directives: Vec::new(),
+        }
+    }
+}
+
+impl LogFilter {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let mut default = LogLevel::None;
+        let mut directives = Vec::new();
+
+        for split in value.split(',') {
+            let split = split.trim();
+            if split.is_empty() {
+                continue;
+            }
+
+            match split.split_once('=') {
+                Some((target, level)) => {
+                    let target = target.trim().replace('-', "_");
+                    if target.is_empty() {
+                        return Err(format!("directive has no target: {}", split));
+                    }
+
+                    let level = level.parse()?;
+                    directives.push(Directive { target, level });
+                }
+                None => default = split.parse()?,
+            }
+        }
+
+        // longest targets first, such that the first match is the most specific one
+        directives.sort_by_key(|x| std::cmp::Reverse(x.target.len()));
+
+        Ok(Self {
+            default,
+            directives,
+        })
+    }
+
+    // returns `None` if the environment variable is not set
+    pub fn from_env() -> Option<Result<Self, String>> {
+        let value = std::env::var(ENV_VAR).ok()?;
+        Some(Self::parse(&value))
+    }
+
+    pub fn level(&self, package: &str, file: &str) -> LogLevel {
+        if self.directives.is_empty() {
+            return self.default;
+        }
+
+        let module_path = module_path(package, file);
+        for directive in self.directives.iter() {
+            let Some(rest) = module_path.strip_prefix(&directive.target) else {
+                continue;
+            };
+
+            if rest.is_empty() || rest.starts_with("::") {
+                return directive.level;
+            }
+        }
+
+        self.default
+    }
+
+    // the most verbose level of all directives. messages below this level are never logged
+    pub fn max_verbosity(&self) -> LogLevel {
+        self.directives
+            .iter()
+            .map(|x| x.level)
+            .fold(self.default, LogLevel::min)
+    }
+}
+
+// derives the module path from the file captured by the `log!` macro, for example the package
+// `advent_of_code_2025` and the file `src/aoc_2025/day_10.rs` result in
+// `advent_of_code_2025::aoc_2025::day_10`
+pub fn module_path(package: &str, file: &str) -> String {
+    let mut module_path = package.replace('-', "_");
+
+    let file = file.replace('\\', "/");
+    let file = match file.strip_prefix("src/") {
+        Some(file) => file,
+        None => match file.rfind("/src/") {
+            Some(index) => &file[index + 5..],
+            None => file.as_str(),
+        },
+    };
+    let file = file.strip_suffix(".rs").unwrap_or(file);
+    let file = file.strip_suffix("/mod").unwrap_or(file);
+
+    if !file.is_empty() && file != "lib" && file != "main" {
+        for module in file.split('/') {
+            module_path.push_str("::");
+            module_path.push_str(module);
+        }
+    }
+
+    module_path
+}
@@ -0,0 +1,243 @@
+use std::collections::HashMap;
+
+use crate::color_string::Color;
+use crate::color_string::Style;
+use crate::color_string::StyledString;
+use crate::constructed_log_message::ConstructedLogMessage;
+use crate::log_message::LogMessage;
+
+// a template for log lines. placeholders are written in braces and may be aligned like in
+// `format!`, with `<`, `>` or `^` followed by a width. literal braces are written as `{{` and
+// `}}`. example:
+//
+//     {time} {level:>7} [{thread}] {file}:{line} {message}
+//
+// available placeholders:
+//
+//     counter  the sequence number of the message
+//     elapsed  milliseconds since the log was initialized
+//     time     the wall clock time in utc
+//     level    the priority of the message
+//     thread   the id and name of the thread, that logged the message
+//     package  the package, that logged the message
+//     module   the module path, that logged the message
+//     file     the file, that logged the message
+//     line     the line, that logged the message
+//     span     the names of all spans the message was logged in, separated by `/`
+//     indent   four spaces per span the message was logged in
+//     message  the message itself
+//     fields   the structured fields of the message, separated by spaces
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFormat {
+    pub segments: Vec<Segment>,
+    // overrides the default style of a placeholder
+    pub styles: HashMap<Placeholder, Style>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Segment {
+    Literal(String),
+    Placeholder(Placeholder, Option<Alignment>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Placeholder {
+    Counter,
+    Elapsed,
+    Time,
+    Level,
+    Thread,
+    Package,
+    Module,
+    File,
+    Line,
+    Span,
+    Indent,
+    Message,
+    Fields,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alignment {
+    Left(usize),
+    Right(usize),
+    Center(usize),
+}
+
+impl std::str::FromStr for Placeholder {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "counter" => Ok(Placeholder::Counter),
+            "elapsed" => Ok(Placeholder::Elapsed),
+            "time" => Ok(Placeholder::Time),
+            "level" => Ok(Placeholder::Level),
+            "thread" => Ok(Placeholder::Thread),
+            "package" => Ok(Placeholder::Package),
+            "module" => Ok(Placeholder::Module),
+            "file" => Ok(Placeholder::File),
+            "line" => Ok(Placeholder::Line),
+            "span" => Ok(Placeholder::Span),
+            "indent" => Ok(Placeholder::Indent),
+            "message" => Ok(Placeholder::Message),
+            "fields" => Ok(Placeholder::Fields),
+            value => Err(format!("unknown placeholder: {{{}}}", value)),
+        }
+    }
+}
+
+impl Alignment {
+    fn parse(value: &str) -> Result<Self, String> {
+        let (alignment, width): (fn(usize) -> Alignment, &str) = match value.chars().next() {
+            Some('<') => (Alignment::Left, &value[1..]),
+            Some('>') => (Alignment::Right, &value[1..]),
+            Some('^') => (Alignment::Center, &value[1..]),
+            _ => (Alignment::Left, value),
+        };
+
+        match width.parse() {
+            Ok(width) => Ok(alignment(width)),
+            Err(_) => Err(format!("invalid alignment: {}", value)),
+        }
+    }
+
+    fn apply(self, value: &str) -> String {
+        match self {
+            Alignment::Left(width) => format!("{:<width$}", value),
+            Alignment::Right(width) => format!("{:>width$}", value),
+            Alignment::Center(width) => format!("{:^width$}", value),
+        }
+    }
+}
+
+impl LogFormat {
+    pub fn parse(template: &str) -> Result<Self, String> {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut chars = template.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    let mut placeholder = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => placeholder.push(c),
+                            None => return Err(format!("unclosed placeholder in: {}", template)),
+                        }
+                    }
+
+                    if !literal.is_empty() {
+                        segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                    }
+
+                    let (name, alignment) = match placeholder.split_once(':') {
+                        Some((name, alignment)) => {
+                            (name, Some(Alignment::parse(alignment.trim())?))
+                        }
+                        None => (placeholder.as_str(), None),
+                    };
+
+                    segments.push(Segment::Placeholder(name.trim().parse()?, alignment));
+                }
+                '}' => return Err(format!("unmatched `}}` in: {}", template)),
+                c => literal.push(c),
+            }
+        }
+
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+
+        Ok(Self {
+            segments,
+            styles: HashMap::new(),
+        })
+    }
+
+    pub fn set_style(&mut self, placeholder: Placeholder, style: Style) {
+        self.styles.insert(placeholder, style);
+    }
+
+    // plain messages have no placeholders to fill. they are returned as they are
+    pub fn fmt(&self, message: &LogMessage, ansi_support: bool) -> String {
+        match message {
+            LogMessage::Constructed(message) => self.fmt_constructed(message, ansi_support),
+            LogMessage::Plain(message) => message.to_owned(),
+        }
+    }
+
+    pub fn fmt_constructed(&self, message: &ConstructedLogMessage, ansi_support: bool) -> String {
+        let mut result = String::new();
+
+        for segment in self.segments.iter() {
+            match segment {
+                Segment::Literal(literal) => {
+                    let literal = StyledString(literal, Style::from(Color::White));
+                    result.push_str(&literal.fmt(ansi_support));
+                }
+                Segment::Placeholder(placeholder, alignment) => {
+                    let value = placeholder_value(*placeholder, message);
+                    let value = match alignment {
+                        Some(alignment) => alignment.apply(&value),
+                        None => value,
+                    };
+
+                    let style = match self.styles.get(placeholder) {
+                        Some(style) => *style,
+                        None => default_style(*placeholder, message),
+                    };
+
+                    result.push_str(&StyledString(&value, style).fmt(ansi_support));
+                }
+            }
+        }
+
+        result
+    }
+}
+
+fn placeholder_value(placeholder: Placeholder, message: &ConstructedLogMessage) -> String {
+    match placeholder {
+        Placeholder::Counter => message.timestamp.raw().to_string(),
+        Placeholder::Elapsed => crate::timestamp::fmt_relative_ms(message.elapsed),
+        Placeholder::Time => crate::timestamp::fmt_iso8601(message.time),
+        Placeholder::Level => message.priority.to_styled_string().0.to_string(),
+        Placeholder::Thread => {
+            crate::thread::fmt(message.thread_id, message.thread_name.as_deref())
+        }
+        Placeholder::Package => message.package.clone(),
+        Placeholder::Module => crate::log_filter::module_path(&message.package, &message.file),
+        Placeholder::File => message.file.clone(),
+        Placeholder::Line => message.line.to_string(),
+        Placeholder::Span => message.span_path.join("/"),
+        Placeholder::Indent => "    ".repeat(message.span_path.len()),
+        Placeholder::Message => message.message.clone(),
+        Placeholder::Fields => message
+            .fields
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(" "),
+    }
+}
+
+// matches the colors of `ConstructedLogMessage::fmt`
+fn default_style(placeholder: Placeholder, message: &ConstructedLogMessage) -> Style {
+    match placeholder {
+        Placeholder::Level => message.priority.to_styled_string().1,
+        Placeholder::Message => Style::from(Color::BrightWhite),
+        Placeholder::Indent => Style::new(),
+        _ => Style::from(Color::White),
+    }
+}
@@ -1,5 +1,7 @@
 use crate::color_string::Color;
 use crate::color_string::ColorString;
+use crate::color_string::Style;
+use crate::color_string::StyledString;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum LogLevel {
@@ -49,7 +51,21 @@ pub const ERROR_COLOR: Color = Color::BrightRed;
 pub const FATAL_COLOR: Color = Color::BrightRed;
 pub const NONE_COLOR: Color = Color::BrightWhite;
 
+pub const TRACE_STYLE: Style = Style::new().foreground(TRACE_COLOR);
+pub const DEBUG_STYLE: Style = Style::new().foreground(DEBUG_COLOR);
+pub const INFO_STYLE: Style = Style::new().foreground(INFO_COLOR);
+pub const WARNING_STYLE: Style = Style::new().foreground(WARNING_COLOR);
+pub const ERROR_STYLE: Style = Style::new().foreground(ERROR_COLOR).bold();
+pub const FATAL_STYLE: Style = Style::new()
+    .foreground(Color::BrightWhite)
+    .background(Color::Red)
+    .bold();
+pub const NONE_STYLE: Style = Style::new().foreground(NONE_COLOR);
+
 impl LogLevel {
+    // the foreground color of each level, as before styles were introduced. the background and
+    // text attributes of the style are lost
+    #[deprecated(note = "use `to_styled_string` instead")]
     pub fn to_color_string(&self) -> ColorString<'_> {
         match *self {
             LogLevel::Trace => ColorString("Trace", TRACE_COLOR),
@@ -61,4 +77,97 @@ impl LogLevel {
             LogLevel::None => ColorString("None", NONE_COLOR),
         }
     }
+
+    pub fn to_styled_string(&self) -> StyledString<'_> {
+        match *self {
+            LogLevel::Trace => StyledString("Trace", TRACE_STYLE),
+            LogLevel::Debug => StyledString("Debug", DEBUG_STYLE),
+            LogLevel::Info => StyledString("Info", INFO_STYLE),
+            LogLevel::Warning => StyledString("Warning", WARNING_STYLE),
+            LogLevel::Error => StyledString("Error", ERROR_STYLE),
+            LogLevel::Fatal => StyledString("Fatal", FATAL_STYLE),
+            LogLevel::None => StyledString("None", NONE_STYLE),
+        }
+    }
+}
+
+impl std::str::FromStr for LogLevel {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_lowercase().as_str() {
+            "trace" => Ok(LogLevel::Trace),
+            "debug" => Ok(LogLevel::Debug),
+            "info" => Ok(LogLevel::Info),
+            "warning" | "warn" => Ok(LogLevel::Warning),
+            "error" => Ok(LogLevel::Error),
+            "fatal" => Ok(LogLevel::Fatal),
+            "none" | "off" => Ok(LogLevel::None),
+            _ => Err(format!("invalid log level: {}", value)),
+        }
+    }
+}
+
+// the most verbose level, that is compiled into the binary. it is set by the `max_level_*` and
+// `release_max_level_*` features. messages above it are removed by the log macros, without
+// evaluating their arguments
+pub const STATIC_MAX_LEVEL: LogLevel = static_max_level();
+
+const fn static_max_level() -> LogLevel {
+    if cfg!(not(debug_assertions)) {
+        if let Some(level) = release_max_level() {
+            return level;
+        }
+    }
+
+    match max_level() {
+        Some(level) => level,
+        None => LogLevel::Trace,
+    }
+}
+
+// if multiple features are enabled, the least verbose one wins
+const fn max_level() -> Option<LogLevel> {
+    if cfg!(feature = "max_level_off") {
+        Some(LogLevel::None)
+    } else if cfg!(feature = "max_level_fatal") {
+        Some(LogLevel::Fatal)
+    } else if cfg!(feature = "max_level_error") {
+        Some(LogLevel::Error)
+    } else if cfg!(feature = "max_level_warning") {
+        Some(LogLevel::Warning)
+    } else if cfg!(feature = "max_level_info") {
+        Some(LogLevel::Info)
+    } else if cfg!(feature = "max_level_debug") {
+        Some(LogLevel::Debug)
+    } else if cfg!(feature = "max_level_trace") {
+        Some(LogLevel::Trace)
+    } else {
+        None
+    }
+}
+
+const fn release_max_level() -> Option<LogLevel> {
+    if cfg!(feature = "release_max_level_off") {
+        Some(LogLevel::None)
+    } else if cfg!(feature = "release_max_level_fatal") {
+        Some(LogLevel::Fatal)
+    } else if cfg!(feature = "release_max_level_error") {
+        Some(LogLevel::Error)
+    } else if cfg!(feature = "release_max_level_warning") {
+        Some(LogLevel::Warning)
+    } else if cfg!(feature = "release_max_level_info") {
+        Some(LogLevel::Info)
+    } else if cfg!(feature = "release_max_level_debug") {
+        Some(LogLevel::Debug)
+    } else if cfg!(feature = "release_max_level_trace") {
+        Some(LogLevel::Trace)
+    } else {
+        None
+    }
+}
+
+// can be evaluated at compile time, such that disabled messages are optimized away
+pub const fn static_enabled(message_priority: LogLevel) -> bool {
+    message_priority as usize >= STATIC_MAX_LEVEL as usize
 }
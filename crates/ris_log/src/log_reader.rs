@@ -0,0 +1,831 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use crate::constructed_log_message::ConstructedLogMessage;
+use crate::counter::Counter;
+use crate::field::Field;
+use crate::field::FieldValue;
+use crate::json;
+use crate::json::JsonValue;
+use crate::log_level::LogLevel;
+use crate::log_message::LogMessage;
+use crate::run::Run;
+
+// filters messages that were read back from a log file. unset criteria match everything
+#[derive(Debug, Default, Clone)]
+pub struct LogQuery {
+    // the least severe level to keep
+    pub min_level: Option<LogLevel>,
+    // a substring of the file, that logged the message
+    pub file: Option<String>,
+    pub since: Option<SystemTime>,
+    pub until: Option<SystemTime>,
+    // a substring of the message or its fields
+    pub text: Option<String>,
+}
+
+impl LogQuery {
+    // plain messages have nothing but text. they only match queries for text
+    pub fn matches(&self, message: &LogMessage) -> bool {
+        let message = match message {
+            LogMessage::Constructed(message) => message,
+            LogMessage::Plain(message) => {
+                let only_text = self.min_level.is_none()
+                    && self.file.is_none()
+                    && self.since.is_none()
+                    && self.until.is_none();
+                let text = self.text.as_deref().unwrap_or_default();
+
+                return only_text && message.contains(text);
+            }
+        };
+
+        if self.min_level.is_some_and(|x| message.priority < x) {
+            return false;
+        }
+
+        if self
+            .file
+            .as_ref()
+            .is_some_and(|x| !message.file.contains(x.as_str()))
+        {
+            return false;
+        }
+
+        if self.since.is_some_and(|x| message.time < x) {
+            return false;
+        }
+
+        if self.until.is_some_and(|x| message.time > x) {
+            return false;
+        }
+
+        if let Some(text) = &self.text {
+            let in_message = message.message.contains(text.as_str());
+            let in_fields = message
+                .fields
+                .iter()
+                .any(|x| x.to_string().contains(text.as_str()));
+
+            if !in_message && !in_fields {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+// reads a log file, that was written by `FileAppender` or `JsonLinesAppender`. the format is
+// detected by the first line
+pub fn read(path: impl AsRef<Path>) -> Result<Vec<LogMessage>, String> {
+    let path = path.as_ref();
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| format!("error while reading {}: {}", path.display(), e))?;
+
+    let is_json = text
+        .lines()
+        .find(|x| !x.trim().is_empty())
+        .is_some_and(|x| x.trim_start().starts_with('{'));
+
+    if is_json {
+        parse_json_lines(&text)
+            .map_err(|e| format!("error while parsing {}: {}", path.display(), e))
+    } else {
+        Ok(parse_plain(&text))
+    }
+}
+
+// sorts messages by their counter. the counter starts over with every run, so messages are only
+// sorted within the run that wrote them, which is marked by a plain message, i.e. `log run 1234.1
+// started at <time>`. runs are ordered by the time they started. messages before the first mark
+// come first
+//
+// the wrap around `Ord` of `Counter` is not a total order over all values, which `sort_by`
+// requires. instead, every counter is compared by its distance to the first message of its run,
+// which agrees with `Ord` as long as a run spans less than half the counter. plain messages have
+// no counter. they stay behind the message before them
+pub fn sort_by_counter(messages: &mut Vec<LogMessage>) {
+    let mut run = None;
+    let mut first_counters = HashMap::new();
+    let mut key = i64::MIN;
+
+    let mut keyed = std::mem::take(messages)
+        .into_iter()
+        .map(|x| {
+            match &x {
+                LogMessage::Constructed(message) => {
+                    let counter = message.timestamp.raw();
+                    let first = *first_counters.entry(run).or_insert(counter);
+                    key = counter.wrapping_sub(first) as i32 as i64;
+                }
+                LogMessage::Plain(message) => {
+                    if let Some(started) = Run::parse(message) {
+                        run = Some(started);
+                        // the mark comes before every message of its run
+                        key = i64::MIN;
+                    }
+                }
+            }
+
+            let run_key = run.map(|x| (x.started, x.process_id, x.index));
+            ((run_key, key), x)
+        })
+        .collect::<Vec<_>>();
+
+    keyed.sort_by_key(|(key, _)| *key);
+    messages.extend(keyed.into_iter().map(|(_, x)| x));
+}
+
+// parses the default layout of `FileAppender`. every part of the header but the priority is
+// optional, such that captured console output can be read as well. custom templates of
+// `LogFormat` cannot be read back
+//
+// a message ends with its foot. lines after the foot, that don't start another message, are read
+// as plain messages, i.e. the report of dropped messages. without a foot, such lines are read as
+// part of the message before them
+//
+// what is not written is left at a default: without a counter, the position in the text is used,
+// the time is the epoch and the thread is #0. trailing `key=value` words are read as fields, even
+// if they were part of the message
+pub fn parse_plain(text: &str) -> Vec<LogMessage> {
+    let mut result = Vec::new();
+    let mut current: Option<(ConstructedLogMessage, Vec<&str>)> = None;
+
+    for line in text.lines() {
+        if let Some((package, file, line)) = parse_foot(line) {
+            if let Some((mut message, lines)) = current.take() {
+                message.package = package.to_string();
+                message.file = file.to_string();
+                message.line = line;
+                result.push(LogMessage::Constructed(finish_plain(message, lines)));
+                continue;
+            }
+        }
+
+        // the message that is still being read counts as well
+        let index = result.len() + current.is_some() as usize;
+        if let Some((message, first_line)) = parse_header(line, index) {
+            if let Some((message, lines)) = current.take() {
+                result.push(LogMessage::Constructed(finish_plain(message, lines)));
+            }
+
+            current = Some((message, vec![first_line]));
+            continue;
+        }
+
+        match current.as_mut() {
+            Some((_, lines)) => lines.push(line),
+            None if line.is_empty() => (),
+            None => result.push(LogMessage::Plain(line.to_string())),
+        }
+    }
+
+    if let Some((message, lines)) = current {
+        result.push(LogMessage::Constructed(finish_plain(message, lines)));
+    }
+
+    result
+}
+
+fn finish_plain(mut message: ConstructedLogMessage, mut lines: Vec<&str>) -> ConstructedLogMessage {
+    // fields are appended to the last line of the message
+    let (last_line, fields) = match lines.pop() {
+        Some(last_line) => split_fields(last_line),
+        None => (String::new(), Vec::new()),
+    };
+
+    let mut text = lines.join("\n");
+    if !lines.is_empty() {
+        text.push('\n');
+    }
+    text.push_str(&last_line);
+
+    message.message = text;
+    message.fields = fields;
+    message
+}
+
+// `    in {package} at {file}:{line}`
+fn parse_foot(line: &str) -> Option<(&str, &str, u32)> {
+    let rest = line.strip_prefix("    in ")?;
+    let (package, location) = rest.split_once(" at ")?;
+    let (file, line) = location.rsplit_once(':')?;
+    let line = line.trim().parse().ok()?;
+
+    if package.contains(' ') {
+        return None;
+    }
+
+    Some((package, file, line))
+}
+
+// `[timestamp] [counter] Priority: [#id name] message`
+fn parse_header(line: &str, index: usize) -> Option<(ConstructedLogMessage, &str)> {
+    let mut message = ConstructedLogMessage {
+        package: String::new(),
+        file: String::new(),
+        line: 0,
+        timestamp: Counter::from_raw(index as u32),
+        elapsed: Duration::ZERO,
+        time: SystemTime::UNIX_EPOCH,
+        thread_id: 0,
+        thread_name: None,
+        priority: LogLevel::None,
+        message: String::new(),
+        fields: Vec::new(),
+        span_path: Vec::new(),
+    };
+
+    // the console indents messages by their spans
+    let mut rest = line.trim_start_matches(' ');
+
+    // the timestamp may be followed by the counter
+    while rest.starts_with('[') && !rest.starts_with("[#") {
+        let (timestamp, remaining) = rest[1..].split_once("] ")?;
+        if let Ok(counter) = timestamp.parse::<u32>() {
+            message.timestamp = Counter::from_raw(counter);
+        } else if let Some(milliseconds) = timestamp.strip_suffix("ms") {
+            let milliseconds = milliseconds.parse::<f64>().ok()?;
+            message.elapsed = Duration::try_from_secs_f64(milliseconds / 1000.0).ok()?;
+        } else {
+            message.time = crate::timestamp::parse_iso8601(timestamp).ok()?;
+        }
+
+        rest = remaining;
+    }
+
+    let (priority, remaining) = match rest.split_once(": ") {
+        Some(split) => split,
+        None => (rest.strip_suffix(':')?, ""),
+    };
+    if priority.is_empty() || !priority.chars().all(|x| x.is_ascii_alphabetic()) {
+        return None;
+    }
+    message.priority = priority.parse().ok()?;
+    rest = remaining;
+
+    let thread = rest.strip_prefix("[#").and_then(|x| x.split_once(']'));
+    if let Some((thread, remaining)) = thread {
+        let (id, name) = match thread.split_once(' ') {
+            Some((id, name)) => (id, Some(name.to_string())),
+            None => (thread, None),
+        };
+
+        if let Ok(id) = id.parse() {
+            message.thread_id = id;
+            message.thread_name = name;
+            rest = remaining.strip_prefix(' ').unwrap_or(remaining);
+        }
+    }
+
+    Some((message, rest))
+}
+
+// splits trailing `key=value` words from a line. returns the rest of the line and the fields
+fn split_fields(line: &str) -> (String, Vec<Field>) {
+    let mut words = Vec::new();
+    let mut position = 0;
+
+    while position < line.len() {
+        let rest = &line[position..];
+        if rest.starts_with(' ') {
+            position += 1;
+            continue;
+        }
+
+        let (len, field) = parse_field(rest);
+        words.push((position, field));
+        position += len;
+    }
+
+    // only the longest run of fields at the end of the line counts
+    let first_field = words
+        .iter()
+        .rposition(|(_, field)| field.is_none())
+        .map(|x| x + 1)
+        .unwrap_or(0);
+
+    let Some(&(start, _)) = words.get(first_field) else {
+        return (line.to_string(), Vec::new());
+    };
+
+    let message = &line[..start];
+    let message = message.strip_suffix(' ').unwrap_or(message);
+    let fields = words
+        .into_iter()
+        .skip(first_field)
+        .filter_map(|(_, field)| field)
+        .collect();
+
+    (message.to_string(), fields)
+}
+
+// parses the word at the start of `value`. returns its length in bytes and the field, if the word
+// is one
+fn parse_field(value: &str) -> (usize, Option<Field>) {
+    let word_len = value.find(' ').unwrap_or(value.len());
+
+    let Some((key, raw_value)) = value[..word_len].split_once('=') else {
+        return (word_len, None);
+    };
+
+    let is_key = !key.is_empty()
+        && key
+            .chars()
+            .all(|x| x.is_alphanumeric() || x == '_' || x == '.' || x == '-');
+    if !is_key {
+        return (word_len, None);
+    }
+
+    // quoted strings may contain spaces. they are written with `{:?}`
+    if raw_value.starts_with('"') {
+        let quoted = &value[key.len() + 1..];
+        return match unquote(quoted) {
+            Some((len, string)) => (key.len() + 1 + len, Some(Field::new(key, string))),
+            None => (word_len, None),
+        };
+    }
+
+    (
+        word_len,
+        Some(Field::new(key, parse_field_value(raw_value))),
+    )
+}
+
+fn parse_field_value(value: &str) -> FieldValue {
+    if let Ok(value) = value.parse::<bool>() {
+        return FieldValue::Bool(value);
+    }
+
+    if let Ok(value) = value.parse::<u64>() {
+        return FieldValue::U64(value);
+    }
+
+    if let Ok(value) = value.parse::<i64>() {
+        return FieldValue::I64(value);
+    }
+
+    if let Ok(value) = value.parse::<f64>() {
+        return FieldValue::F64(value);
+    }
+
+    if let Some(duration) = parse_duration(value) {
+        return FieldValue::Duration(duration);
+    }
+
+    FieldValue::Str(value.to_string())
+}
+
+// the inverse of `Duration`s debug format, i.e. `1.5s`, `64.194µs` or `0ns`
+fn parse_duration(value: &str) -> Option<Duration> {
+    let units = [("ns", 1e-9), ("µs", 1e-6), ("ms", 1e-3), ("s", 1.0)];
+
+    for (suffix, factor) in units {
+        if let Some(number) = value.strip_suffix(suffix) {
+            if number.is_empty() || !number.chars().all(|x| x.is_ascii_digit() || x == '.') {
+                return None;
+            }
+
+            let number = number.parse::<f64>().ok()?;
+            return Duration::try_from_secs_f64(number * factor).ok();
+        }
+    }
+
+    None
+}
+
+// the inverse of `str`s debug format. returns the length of the quoted string in bytes, including
+// the quotes, and its content
+fn unquote(value: &str) -> Option<(usize, String)> {
+    let mut chars = value.char_indices();
+    if chars.next()?.1 != '"' {
+        return None;
+    }
+
+    let mut result = String::new();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((i + 1, result)),
+            '\\' => match chars.next()?.1 {
+                'n' => result.push('\n'),
+                'r' => result.push('\r'),
+                't' => result.push('\t'),
+                '0' => result.push('\0'),
+                'u' => {
+                    let mut code = String::new();
+                    if chars.next()?.1 != '{' {
+                        return None;
+                    }
+
+                    loop {
+                        match chars.next()?.1 {
+                            '}' => break,
+                            c => code.push(c),
+                        }
+                    }
+
+                    let code = u32::from_str_radix(&code, 16).ok()?;
+                    result.push(char::from_u32(code)?);
+                }
+                c => result.push(c),
+            },
+            c => result.push(c),
+        }
+    }
+
+    None
+}
+
+// parses the output of `JsonLinesAppender`. durations in fields are written as seconds and read
+// back as floats
+pub fn parse_json_lines(text: &str) -> Result<Vec<LogMessage>, String> {
+    let mut result = Vec::new();
+
+    for (i, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let value = json::parse(line).map_err(|e| format!("line {}: {}", i + 1, e))?;
+        // plain messages carry nothing but text
+        if value.get("priority").is_none() {
+            let message = value
+                .get("message")
+                .and_then(|x| x.as_str())
+                .ok_or_else(|| format!("line {}: missing `message`", i + 1))?;
+            result.push(LogMessage::Plain(message.to_string()));
+            continue;
+        }
+
+        let message = json_to_message(&value).map_err(|e| format!("line {}: {}", i + 1, e))?;
+        result.push(LogMessage::Constructed(message));
+    }
+
+    Ok(result)
+}
+
+fn json_to_message(value: &JsonValue) -> Result<ConstructedLogMessage, String> {
+    let get = |key: &str| value.get(key).ok_or_else(|| format!("missing `{}`", key));
+    let get_str = |key: &str| {
+        get(key)?
+            .as_str()
+            .ok_or_else(|| format!("`{}` is not a string", key))
+    };
+    let get_u64 = |key: &str| {
+        get(key)?
+            .as_u64()
+            .ok_or_else(|| format!("`{}` is not an unsigned integer", key))
+    };
+
+    let elapsed = get("elapsed")?
+        .as_f64()
+        .and_then(|x| Duration::try_from_secs_f64(x).ok())
+        .ok_or("`elapsed` is not a duration")?;
+
+    let time = crate::timestamp::parse_iso8601(get_str("time")?)?;
+
+    let thread_name = match get("thread_name")? {
+        JsonValue::Null => None,
+        JsonValue::String(name) => Some(name.clone()),
+        _ => return Err(String::from("`thread_name` is not a string")),
+    };
+
+    let fields = match get("fields")? {
+        JsonValue::Object(members) => members
+            .iter()
+            .map(|(key, value)| json_to_field_value(value).map(|x| Field::new(key, x)))
+            .collect::<Result<Vec<_>, _>>()?,
+        _ => return Err(String::from("`fields` is not an object")),
+    };
+
+    let span_path = match get("span_path")? {
+        JsonValue::Array(items) => items
+            .iter()
+            .map(|x| x.as_str().map(String::from))
+            .collect::<Option<Vec<_>>>()
+            .ok_or("`span_path` contains a value that is not a string")?,
+        _ => return Err(String::from("`span_path` is not an array")),
+    };
+
+    Ok(ConstructedLogMessage {
+        package: get_str("package")?.to_string(),
+        file: get_str("file")?.to_string(),
+        line: get_u64("line")? as u32,
+        timestamp: Counter::from_raw(get_u64("timestamp")? as u32),
+        elapsed,
+        time,
+        thread_id: get_u64("thread_id")?,
+        thread_name,
+        priority: get_str("priority")?.parse()?,
+        message: get_str("message")?.to_string(),
+        fields,
+        span_path,
+    })
+}
+
+fn json_to_field_value(value: &JsonValue) -> Result<FieldValue, String> {
+    match value {
+        // non finite floats are written as null
+        JsonValue::Null => Ok(FieldValue::F64(f64::NAN)),
+        JsonValue::Bool(value) => Ok(FieldValue::Bool(*value)),
+        JsonValue::String(value) => Ok(FieldValue::Str(value.clone())),
+        JsonValue::Number(number) => Ok(parse_field_value(number)),
+        _ => Err(String::from("fields must not contain arrays or objects")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::file_appender::DEFAULT_FORMAT_ARGS;
+
+    fn message(counter: u32, text: &str) -> ConstructedLogMessage {
+        ConstructedLogMessage {
+            package: String::from("ris_log"),
+            file: String::from("src/log_reader.rs"),
+            line: 42,
+            timestamp: Counter::from_raw(counter),
+            elapsed: Duration::ZERO,
+            time: SystemTime::UNIX_EPOCH + Duration::from_millis(1_765_375_402_123),
+            thread_id: 1,
+            thread_name: Some(String::from("main")),
+            priority: LogLevel::Info,
+            message: text.to_string(),
+            fields: Vec::new(),
+            span_path: Vec::new(),
+        }
+    }
+
+    fn constructed(message: &LogMessage) -> &ConstructedLogMessage {
+        match message {
+            LogMessage::Constructed(message) => message,
+            LogMessage::Plain(message) => {
+                panic!("expected a constructed message, got {:?}", message)
+            }
+        }
+    }
+
+    fn texts(messages: &[LogMessage]) -> Vec<String> {
+        messages
+            .iter()
+            .map(|x| match x {
+                LogMessage::Constructed(message) => message.message.clone(),
+                LogMessage::Plain(message) => message.clone(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn unquote_reads_escapes() {
+        assert_eq!(unquote(r#""""#), Some((2, String::new())));
+        assert_eq!(
+            unquote(r#""a \"b\" \\ \n\r\t\0" rest"#),
+            Some((21, String::from("a \"b\" \\ \n\r\t\0")))
+        );
+        assert_eq!(
+            unquote(r#""\u{e9}\u{1f600}""#),
+            Some((17, String::from("é😀")))
+        );
+    }
+
+    #[test]
+    fn unquote_rejects_malformed_strings() {
+        assert_eq!(unquote("unquoted"), None);
+        assert_eq!(unquote(r#""unterminated"#), None);
+        assert_eq!(unquote(r#""escaped end\""#), None);
+        assert_eq!(unquote(r#""\u{zz}""#), None);
+        assert_eq!(unquote(r#""\u00e9""#), None);
+        assert_eq!(unquote(r#""\u{d800}""#), None);
+    }
+
+    #[test]
+    fn unquote_is_the_inverse_of_debug() {
+        for value in ["", "a b", "\"=\"", "tab\tnew\nline", "\u{1}é😀"] {
+            let quoted = format!("{:?}", value);
+            assert_eq!(unquote(&quoted), Some((quoted.len(), value.to_string())));
+        }
+    }
+
+    #[test]
+    fn split_fields_only_reads_trailing_fields() {
+        let (message, fields) = split_fields(r#"a=1 solved day count=3 name="a b" ok=true"#);
+        assert_eq!(message, "a=1 solved day");
+        assert_eq!(
+            fields,
+            vec![
+                Field::new("count", 3u64),
+                Field::new("name", "a b"),
+                Field::new("ok", true),
+            ]
+        );
+
+        let (message, fields) = split_fields("no fields here");
+        assert_eq!(message, "no fields here");
+        assert!(fields.is_empty());
+    }
+
+    #[test]
+    fn parse_field_value_reads_every_kind() {
+        assert_eq!(parse_field_value("true"), FieldValue::Bool(true));
+        assert_eq!(parse_field_value("7"), FieldValue::U64(7));
+        assert_eq!(parse_field_value("-7"), FieldValue::I64(-7));
+        assert_eq!(parse_field_value("1.5"), FieldValue::F64(1.5));
+        assert_eq!(
+            parse_field_value("64.194µs"),
+            FieldValue::Duration(Duration::from_nanos(64194))
+        );
+        assert_eq!(
+            parse_field_value("1s2"),
+            FieldValue::Str(String::from("1s2"))
+        );
+    }
+
+    #[test]
+    fn parse_plain_is_the_inverse_of_the_default_format() {
+        let mut first = message(3, "first line\nsecond line");
+        first.fields = vec![Field::new("day", 5u64), Field::new("name", "a b")];
+        let mut second = message(4, "without fields");
+        second.priority = LogLevel::Warning;
+        second.thread_name = None;
+
+        let text = format!(
+            "{}\n{}\n",
+            first.fmt(DEFAULT_FORMAT_ARGS),
+            second.fmt(DEFAULT_FORMAT_ARGS),
+        );
+        let parsed = parse_plain(&text);
+        assert_eq!(parsed.len(), 2, "{}", text);
+
+        for (expected, actual) in [first, second].iter().zip(parsed.iter()) {
+            let actual = constructed(actual);
+            assert_eq!(actual.message, expected.message);
+            assert_eq!(actual.fields, expected.fields);
+            assert_eq!(actual.timestamp.raw(), expected.timestamp.raw());
+            assert_eq!(actual.time, expected.time);
+            assert_eq!(actual.priority, expected.priority);
+            assert_eq!(actual.thread_id, expected.thread_id);
+            assert_eq!(actual.thread_name, expected.thread_name);
+            assert_eq!(actual.package, expected.package);
+            assert_eq!(actual.file, expected.file);
+            assert_eq!(actual.line, expected.line);
+        }
+    }
+
+    #[test]
+    fn parse_plain_reads_lines_after_a_foot_as_plain_messages() {
+        let run = Run {
+            process_id: 1234,
+            index: 1,
+            started: SystemTime::UNIX_EPOCH,
+        };
+        let text = format!(
+            "{}\n{}\n3 messages were dropped\n\n",
+            run,
+            message(0, "hello").fmt(DEFAULT_FORMAT_ARGS),
+        );
+
+        let parsed = parse_plain(&text);
+        assert_eq!(
+            texts(&parsed),
+            vec![
+                run.to_string(),
+                String::from("hello"),
+                String::from("3 messages were dropped")
+            ]
+        );
+        assert!(matches!(parsed[0], LogMessage::Plain(_)));
+        assert!(matches!(parsed[2], LogMessage::Plain(_)));
+    }
+
+    #[test]
+    fn parse_plain_reads_console_output() {
+        let parsed = parse_plain("  info: [#2 worker] started\nerror: failed\n  continued");
+
+        let first = constructed(&parsed[0]);
+        assert_eq!(first.priority, LogLevel::Info);
+        assert_eq!(first.thread_id, 2);
+        assert_eq!(first.thread_name.as_deref(), Some("worker"));
+        assert_eq!(first.message, "started");
+
+        let second = constructed(&parsed[1]);
+        assert_eq!(second.priority, LogLevel::Error);
+        assert_eq!(second.message, "failed\n  continued");
+        // without a counter, the position is used
+        assert_eq!(second.timestamp.raw(), 1);
+    }
+
+    #[test]
+    fn parse_plain_keeps_malformed_headers_as_text() {
+        let parsed = parse_plain("[not a time] info: hello\nnot a header");
+        assert_eq!(
+            texts(&parsed),
+            vec![
+                String::from("[not a time] info: hello"),
+                String::from("not a header")
+            ]
+        );
+        assert!(parsed.iter().all(|x| matches!(x, LogMessage::Plain(_))));
+    }
+
+    #[test]
+    fn parse_json_lines_reads_messages_and_plain_lines() {
+        let line = r#"{"package":"ris_log","file":"src/log_reader.rs","line":42,"timestamp":7,"elapsed":0.5,"time":"2025-12-10T14:03:22.123Z","thread_id":1,"thread_name":null,"priority":"Info","message":"a \"quoted\"\nmessage","fields":{"day":5,"name":"a b","ok":true},"span_path":["day 5"]}"#;
+        let text = format!("{}\n\n{{\"message\":\"3 messages were dropped\"}}\n", line);
+
+        let parsed = parse_json_lines(&text).unwrap();
+        assert_eq!(parsed.len(), 2);
+
+        let message = constructed(&parsed[0]);
+        assert_eq!(message.message, "a \"quoted\"\nmessage");
+        assert_eq!(message.timestamp.raw(), 7);
+        assert_eq!(message.elapsed, Duration::from_millis(500));
+        assert_eq!(
+            message.time,
+            SystemTime::UNIX_EPOCH + Duration::from_millis(1_765_375_402_123)
+        );
+        assert_eq!(message.thread_name, None);
+        assert_eq!(message.priority, LogLevel::Info);
+        assert_eq!(
+            message.fields,
+            vec![
+                Field::new("day", 5u64),
+                Field::new("name", "a b"),
+                Field::new("ok", true),
+            ]
+        );
+        assert_eq!(message.span_path, vec![String::from("day 5")]);
+
+        assert!(matches!(&parsed[1], LogMessage::Plain(x) if x == "3 messages were dropped"));
+    }
+
+    #[test]
+    fn parse_json_lines_reports_the_malformed_line() {
+        let cases = [
+            ("{\"message\":\"ok\"}\n{\"message\":", "line 2: "),
+            ("[1, 2]", "line 1: missing `message`"),
+            ("{\"priority\":\"Info\"}", "line 1: missing `"),
+            (
+                "{\"message\":\"a\"}\n\n{\"priority\":\"Info\",\"time\":\"2025-02-31\"}",
+                "line 3: ",
+            ),
+        ];
+
+        for (text, expected) in cases {
+            let Err(error) = parse_json_lines(text) else {
+                panic!("expected an error for {:?}", text);
+            };
+            assert!(error.starts_with(expected), "{}", error);
+        }
+    }
+
+    #[test]
+    fn sort_by_counter_sorts_within_runs() {
+        let run = |index, started| Run {
+            process_id: 1234,
+            index,
+            started: SystemTime::UNIX_EPOCH + Duration::from_secs(started),
+        };
+        let constructed = |counter, text| LogMessage::Constructed(message(counter, text));
+
+        // the later run was appended first, i.e. by a rotated file that is read first
+        let mut messages = vec![
+            LogMessage::Plain(run(2, 20).to_string()),
+            constructed(1, "second run 1"),
+            constructed(0, "second run 0"),
+            LogMessage::Plain(run(1, 10).to_string()),
+            constructed(1, "first run 1"),
+            LogMessage::Plain(String::from("dropped")),
+            constructed(0, "first run 0"),
+        ];
+        sort_by_counter(&mut messages);
+
+        assert_eq!(
+            texts(&messages),
+            vec![
+                run(1, 10).to_string(),
+                String::from("first run 0"),
+                String::from("first run 1"),
+                String::from("dropped"),
+                run(2, 20).to_string(),
+                String::from("second run 0"),
+                String::from("second run 1"),
+            ]
+        );
+    }
+
+    #[test]
+    fn sort_by_counter_handles_wrap_around() {
+        let mut messages = vec![
+            LogMessage::Constructed(message(u32::MAX, "before")),
+            LogMessage::Constructed(message(1, "after")),
+            LogMessage::Constructed(message(0, "between")),
+        ];
+        sort_by_counter(&mut messages);
+
+        assert_eq!(texts(&messages), vec!["before", "between", "after"]);
+    }
+}
@@ -0,0 +1,82 @@
+use std::backtrace::Backtrace;
+use std::io::Write;
+use std::time::SystemTime;
+
+use crate::constructed_log_message::ConstructedLogMessage;
+use crate::log_level::LogLevel;
+use crate::log_message::LogMessage;
+
+// logs panics as fatal messages and waits until they are written, before calling the hook that
+// was installed before. this way the panic and all messages before it are written, even if the
+// panic aborts the process. panics are attributed to `package`, usually the one of the binary:
+//
+//     ris_log::panic_hook::install(env!("CARGO_PKG_NAME"), false);
+//
+// a backtrace is always captured if `backtrace` is true, otherwise only if `RUST_BACKTRACE` is set
+pub fn install(package: &'static str, backtrace: bool) {
+    let previous_hook = std::panic::take_hook();
+
+    std::panic::set_hook(Box::new(move |info| {
+        log_panic(package, backtrace, info);
+        previous_hook(info);
+    }));
+}
+
+fn log_panic(package: &'static str, backtrace: bool, info: &std::panic::PanicHookInfo) {
+    let (file, line, column) = match info.location() {
+        Some(location) => (location.file(), location.line(), location.column()),
+        None => ("", 0, 0),
+    };
+
+    // when an appender panics, the log cannot be used anymore: the log thread cannot wait for
+    // itself and in synchronous mode, this thread already holds the appenders. stderr is written
+    // directly instead, even while the log shuts down
+    let holds_appenders = crate::log::holds_appenders();
+    if !holds_appenders && !crate::log::enabled(LogLevel::Fatal, package, file) {
+        return;
+    }
+
+    let payload = if let Some(payload) = info.payload().downcast_ref::<&str>() {
+        payload.to_string()
+    } else if let Some(payload) = info.payload().downcast_ref::<String>() {
+        payload.clone()
+    } else {
+        String::from("unknown panic payload")
+    };
+
+    let mut message = format!("panicked at {}:{}:{}: {}", file, line, column, payload);
+
+    let backtrace = if backtrace {
+        Backtrace::force_capture()
+    } else {
+        Backtrace::capture()
+    };
+    if backtrace.status() == std::backtrace::BacktraceStatus::Captured {
+        message.push_str(&format!("\nbacktrace:\n{}", backtrace));
+    }
+
+    let constructed_log = ConstructedLogMessage {
+        package: package.to_string(),
+        file: file.to_string(),
+        line,
+        timestamp: crate::log::get_timestamp(),
+        elapsed: crate::timestamp::elapsed(),
+        time: SystemTime::now(),
+        thread_id: crate::thread::id(),
+        thread_name: crate::thread::name(),
+        priority: LogLevel::Fatal,
+        message,
+        fields: Vec::new(),
+        span_path: crate::span::current_path(),
+    };
+
+    // errors are ignored, since a closed stderr may be the cause of the panic
+    if holds_appenders {
+        let message = constructed_log.fmt(crate::console_appender::DEFAULT_FORMAT_ARGS);
+        let _ = writeln!(std::io::stderr(), "{}", message);
+        return;
+    }
+
+    crate::log::forward_to_appenders(LogMessage::Constructed(constructed_log));
+    crate::log::flush();
+}
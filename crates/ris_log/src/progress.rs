@@ -0,0 +1,273 @@
+use std::io::IsTerminal;
+use std::io::Write;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+
+use crate::constructed_log_message::ConstructedLogMessage;
+use crate::log_level::LogLevel;
+use crate::log_message::LogMessage;
+
+pub const PROGRESS_LEVEL: LogLevel = LogLevel::Info;
+
+const TERMINAL_INTERVAL: Duration = Duration::from_millis(100);
+const LINES_INTERVAL: Duration = Duration::from_secs(2);
+
+static MODE: AtomicUsize = AtomicUsize::new(ProgressMode::Auto as usize);
+
+// the progress line, that is currently shown on stderr in terminal mode. everything else that
+// writes to stderr goes through `eprintln_above`, such that it doesn't overwrite the line
+static TERMINAL_LINE: Mutex<Option<String>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressMode {
+    // terminal if stderr is a terminal that supports ansi escape codes, lines otherwise
+    Auto = 0,
+    // a single line on stderr, that is updated in place
+    Terminal = 1,
+    // periodic log messages
+    Lines = 2,
+    // no progress is reported at all, i.e. when the output is meant to be read by a machine
+    Hidden = 3,
+}
+
+impl From<usize> for ProgressMode {
+    fn from(value: usize) -> Self {
+        match value {
+            0 => ProgressMode::Auto,
+            1 => ProgressMode::Terminal,
+            2 => ProgressMode::Lines,
+            3 => ProgressMode::Hidden,
+            _ => panic!("{} cannot be mapped to a progress mode", value),
+        }
+    }
+}
+
+pub fn set_mode(mode: ProgressMode) {
+    MODE.store(mode as usize, Ordering::Relaxed);
+}
+
+pub fn mode() -> ProgressMode {
+    ProgressMode::from(MODE.load(Ordering::Relaxed))
+}
+
+// writes a line to stderr. if a progress line is shown, the line is written above it
+pub fn eprintln_above(line: &str) {
+    let terminal_line = match TERMINAL_LINE.lock() {
+        Ok(terminal_line) => terminal_line,
+        Err(e) => e.into_inner(),
+    };
+
+    // errors are ignored, there is nowhere left to report them
+    let mut stderr = std::io::stderr().lock();
+    match terminal_line.as_deref() {
+        Some(terminal_line) => {
+            let clear = clear_line(terminal_line);
+            let _ = write!(stderr, "{}{}\n{}", clear, line, terminal_line);
+        }
+        None => {
+            let _ = writeln!(stderr, "{}", line);
+        }
+    }
+    let _ = stderr.flush();
+}
+
+// moves the cursor to the start of the line and clears it. without ansi support, the line is
+// overwritten with spaces instead
+fn clear_line(line: &str) -> String {
+    if crate::color_string::stderr_supports_ansi() {
+        String::from("\r\u{001B}[2K")
+    } else {
+        format!("\r{}\r", " ".repeat(line.chars().count()))
+    }
+}
+
+pub struct Progress {
+    label: String,
+    package: &'static str,
+    file: &'static str,
+    line: u32,
+    total: usize,
+    current: AtomicUsize,
+    mode: ProgressMode,
+    start: Instant,
+    last_report: Mutex<Instant>,
+}
+
+impl Progress {
+    // the progress is reported like a message logged at `PROGRESS_LEVEL` in the given package
+    // and file. if such a message would be filtered, no progress is reported. use the
+    // `progress!` macro to report it from the caller
+    pub fn new(
+        label: impl Into<String>,
+        total: usize,
+        package: &'static str,
+        file: &'static str,
+        line: u32,
+    ) -> Self {
+        let mode = match mode() {
+            ProgressMode::Auto => {
+                let is_terminal = std::io::stderr().is_terminal();
+                if is_terminal && crate::color_string::stderr_supports_ansi() {
+                    ProgressMode::Terminal
+                } else {
+                    ProgressMode::Lines
+                }
+            }
+            mode => mode,
+        };
+
+        let start = Instant::now();
+
+        Self {
+            label: label.into(),
+            package,
+            file,
+            line,
+            total,
+            current: AtomicUsize::new(0),
+            mode,
+            start,
+            last_report: Mutex::new(start),
+        }
+    }
+
+    pub fn inc(&self) {
+        self.add(1);
+    }
+
+    pub fn add(&self, amount: usize) {
+        let previous = self.current.fetch_add(amount, Ordering::Relaxed);
+        self.report(previous + amount, false);
+    }
+
+    pub fn current(&self) -> usize {
+        self.current.load(Ordering::Relaxed)
+    }
+
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    fn report(&self, current: usize, force: bool) {
+        let interval = match self.mode {
+            ProgressMode::Terminal => TERMINAL_INTERVAL,
+            ProgressMode::Lines => LINES_INTERVAL,
+            ProgressMode::Auto | ProgressMode::Hidden => return,
+        };
+
+        if !crate::log::enabled(PROGRESS_LEVEL, self.package, self.file) {
+            return;
+        }
+
+        // multiple threads may report at once. if another one is already reporting, this report
+        // can be skipped
+        let Ok(mut last_report) = self.last_report.try_lock() else {
+            return;
+        };
+
+        let now = Instant::now();
+        if !force && now - *last_report < interval {
+            return;
+        }
+        *last_report = now;
+
+        let line = self.fmt(current, now);
+        match self.mode {
+            ProgressMode::Terminal => {
+                let mut terminal_line = match TERMINAL_LINE.lock() {
+                    Ok(terminal_line) => terminal_line,
+                    Err(e) => e.into_inner(),
+                };
+
+                let clear = match terminal_line.as_deref() {
+                    Some(previous) => clear_line(previous),
+                    None => String::new(),
+                };
+
+                let mut stderr = std::io::stderr().lock();
+                let _ = write!(stderr, "{}{}", clear, line);
+                let _ = stderr.flush();
+                *terminal_line = Some(line);
+            }
+            ProgressMode::Lines => self.log(line),
+            ProgressMode::Auto | ProgressMode::Hidden => (),
+        }
+    }
+
+    fn log(&self, message: String) {
+        let constructed_log = ConstructedLogMessage {
+            package: self.package.to_string(),
+            file: self.file.to_string(),
+            line: self.line,
+            timestamp: crate::log::get_timestamp(),
+            elapsed: crate::timestamp::elapsed(),
+            time: SystemTime::now(),
+            thread_id: crate::thread::id(),
+            thread_name: crate::thread::name(),
+            priority: PROGRESS_LEVEL,
+            message,
+            fields: Vec::new(),
+            span_path: crate::span::current_path(),
+        };
+
+        crate::log::forward_to_appenders(LogMessage::Constructed(constructed_log));
+    }
+
+    fn fmt(&self, current: usize, now: Instant) -> String {
+        let elapsed = now - self.start;
+        let percentage = if self.total == 0 {
+            100.0
+        } else {
+            100.0 * current as f32 / self.total as f32
+        };
+
+        let mut result = format!(
+            "{}... {}/{} {:.1}%",
+            self.label, current, self.total, percentage,
+        );
+
+        if current >= self.total {
+            result.push_str(&format!(" done in {:.1?}", elapsed));
+        } else if current > 0 {
+            let remaining = (self.total - current) as f64 / current as f64;
+            let eta = Duration::from_secs_f64(elapsed.as_secs_f64() * remaining);
+            result.push_str(&format!(" eta {:.1?}", eta));
+        }
+
+        result
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        let current = self.current();
+        self.report(current, true);
+
+        // the final progress line is kept. following output starts below it
+        if self.mode == ProgressMode::Terminal {
+            let mut terminal_line = match TERMINAL_LINE.lock() {
+                Ok(terminal_line) => terminal_line,
+                Err(e) => e.into_inner(),
+            };
+
+            if terminal_line.take().is_some() {
+                let mut stderr = std::io::stderr().lock();
+                let _ = writeln!(stderr);
+            }
+        }
+    }
+}
+
+// usage:
+//
+//     let progress = ris_log::progress!("run machines", machines.len());
+#[macro_export]
+macro_rules! progress {
+    ($label:expr, $total:expr) => {
+        ris_log::progress::Progress::new($label, $total, env!("CARGO_PKG_NAME"), file!(), line!())
+    };
+}
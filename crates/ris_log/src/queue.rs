@@ -0,0 +1,214 @@
+use std::collections::VecDeque;
+use std::sync::Condvar;
+use std::sync::Mutex;
+
+use crate::log_level::LogLevel;
+
+pub const DEFAULT_CAPACITY: usize = 10_000;
+
+// what happens to a message, when the queue is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    // wait until the log thread made room
+    Block,
+    // drop the message that is about to be queued
+    DropNewest,
+    // drop the oldest message in the queue to make room
+    DropOldest,
+    // drop messages with a priority below the given level, block for all others
+    DropBelow(LogLevel),
+}
+
+pub trait QueueItem {
+    // items without priority are never dropped and ignore the capacity
+    fn priority(&self) -> Option<LogLevel>;
+}
+
+// a bounded multi producer, single consumer queue
+pub struct Queue<T> {
+    state: Mutex<State<T>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+}
+
+struct State<T> {
+    items: VecDeque<T>,
+    capacity: usize,
+    policy: OverflowPolicy,
+    dropped: usize,
+    closed: bool,
+    // waking a thread is expensive, even if none is waiting. as such it is only done when
+    // one actually waits
+    consumer_waiting: bool,
+    producers_waiting: usize,
+}
+
+impl<T: QueueItem> Queue<T> {
+    pub fn new(capacity: usize, policy: OverflowPolicy) -> Self {
+        let state = State {
+            items: VecDeque::new(),
+            capacity,
+            policy,
+            dropped: 0,
+            closed: false,
+            consumer_waiting: false,
+            producers_waiting: 0,
+        };
+
+        Self {
+            state: Mutex::new(state),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+        }
+    }
+
+    pub fn push(&self, item: T) {
+        let mut state = match self.state.lock() {
+            Ok(state) => state,
+            Err(e) => {
+                eprintln!("error while pushing to log queue: {}", e);
+                return;
+            }
+        };
+
+        // nothing is popped from a closed queue anymore. waiting for room would block forever
+        if state.closed {
+            return;
+        }
+
+        if let Some(priority) = item.priority() {
+            if state.items.len() >= state.capacity {
+                let block = match state.policy {
+                    OverflowPolicy::Block => true,
+                    OverflowPolicy::DropNewest => {
+                        state.dropped += 1;
+                        return;
+                    }
+                    OverflowPolicy::DropOldest => {
+                        let oldest = state.items.iter().position(|x| x.priority().is_some());
+                        if let Some(oldest) = oldest {
+                            state.items.remove(oldest);
+                            state.dropped += 1;
+                        }
+                        false
+                    }
+                    OverflowPolicy::DropBelow(level) => {
+                        if priority < level {
+                            state.dropped += 1;
+                            return;
+                        }
+                        true
+                    }
+                };
+
+                if block {
+                    while state.items.len() >= state.capacity && !state.closed {
+                        state.producers_waiting += 1;
+                        state = match self.not_full.wait(state) {
+                            Ok(state) => state,
+                            Err(e) => {
+                                eprintln!("error while waiting for log queue: {}", e);
+                                return;
+                            }
+                        };
+                        state.producers_waiting -= 1;
+                    }
+
+                    if state.closed {
+                        return;
+                    }
+                }
+            }
+        }
+
+        state.items.push_back(item);
+        if state.consumer_waiting {
+            self.not_empty.notify_one();
+        }
+    }
+
+    // blocks until an item is available. returns `None` once the queue is closed and empty
+    pub fn pop(&self) -> Option<T> {
+        let mut state = match self.state.lock() {
+            Ok(state) => state,
+            Err(e) => {
+                eprintln!("error while popping from log queue: {}", e);
+                return None;
+            }
+        };
+
+        loop {
+            if let Some(item) = state.items.pop_front() {
+                // waking producers one by one would switch threads for every message. instead
+                // they are woken all at once, when the queue has room for many of them
+                if state.producers_waiting > 0 && state.items.len() <= state.capacity / 2 {
+                    self.not_full.notify_all();
+                }
+                return Some(item);
+            }
+
+            if state.closed {
+                return None;
+            }
+
+            state.consumer_waiting = true;
+            state = match self.not_empty.wait(state) {
+                Ok(state) => state,
+                Err(e) => {
+                    eprintln!("error while waiting for log queue: {}", e);
+                    return None;
+                }
+            };
+            state.consumer_waiting = false;
+        }
+    }
+
+    // items that are already queued can still be popped. pushing has no effect anymore
+    pub fn close(&self) {
+        match self.state.lock() {
+            Ok(mut state) => state.closed = true,
+            Err(e) => eprintln!("error while closing log queue: {}", e),
+        }
+
+        self.not_empty.notify_all();
+        self.not_full.notify_all();
+    }
+
+    // drops every queued item, i.e. when the consumer is gone
+    pub fn clear(&self) {
+        match self.state.lock() {
+            Ok(mut state) => state.items.clear(),
+            Err(e) => eprintln!("error while clearing log queue: {}", e),
+        }
+
+        self.not_full.notify_all();
+    }
+
+    pub fn dropped(&self) -> usize {
+        match self.state.lock() {
+            Ok(state) => state.dropped,
+            Err(e) => {
+                eprintln!("error while getting dropped messages: {}", e);
+                0
+            }
+        }
+    }
+
+    pub fn set_capacity(&self, capacity: usize) {
+        match self.state.lock() {
+            Ok(mut state) => state.capacity = capacity,
+            Err(e) => eprintln!("error while setting log queue capacity: {}", e),
+        }
+
+        self.not_full.notify_all();
+    }
+
+    pub fn set_policy(&self, policy: OverflowPolicy) {
+        match self.state.lock() {
+            Ok(mut state) => state.policy = policy,
+            Err(e) => eprintln!("error while setting overflow policy: {}", e),
+        }
+
+        self.not_full.notify_all();
+    }
+}
@@ -0,0 +1,113 @@
+// logs only every n-th call of this callsite, starting with the first. calls are counted per
+// callsite and across all threads:
+//
+//     ris_log::info_every!(1000, "configured machine {}", i);
+#[macro_export]
+macro_rules! log_every {
+    ($priority:expr, $n:expr, $($arg:tt)*) => {{
+        static CALLS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+        if ris_log::log_level::static_enabled($priority) {
+            let n = usize::max($n, 1);
+            if CALLS.fetch_add(1, std::sync::atomic::Ordering::Relaxed) % n == 0 {
+                ris_log::log!($priority, $($arg)*);
+            }
+        }
+    }};
+}
+
+// logs only the first call of this callsite
+#[macro_export]
+macro_rules! log_once {
+    ($priority:expr, $($arg:tt)*) => {{
+        static DONE: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+        if ris_log::log_level::static_enabled($priority)
+            && !DONE.swap(true, std::sync::atomic::Ordering::Relaxed)
+        {
+            ris_log::log!($priority, $($arg)*);
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! trace_every {
+    ($n:expr, $($arg:tt)*) => {
+        ris_log::log_every!(ris_log::log_level::LogLevel::Trace, $n, $($arg)*);
+    };
+}
+
+#[macro_export]
+macro_rules! debug_every {
+    ($n:expr, $($arg:tt)*) => {
+        ris_log::log_every!(ris_log::log_level::LogLevel::Debug, $n, $($arg)*);
+    };
+}
+
+#[macro_export]
+macro_rules! info_every {
+    ($n:expr, $($arg:tt)*) => {
+        ris_log::log_every!(ris_log::log_level::LogLevel::Info, $n, $($arg)*);
+    };
+}
+
+#[macro_export]
+macro_rules! warning_every {
+    ($n:expr, $($arg:tt)*) => {
+        ris_log::log_every!(ris_log::log_level::LogLevel::Warning, $n, $($arg)*);
+    };
+}
+
+#[macro_export]
+macro_rules! error_every {
+    ($n:expr, $($arg:tt)*) => {
+        ris_log::log_every!(ris_log::log_level::LogLevel::Error, $n, $($arg)*);
+    };
+}
+
+#[macro_export]
+macro_rules! fatal_every {
+    ($n:expr, $($arg:tt)*) => {
+        ris_log::log_every!(ris_log::log_level::LogLevel::Fatal, $n, $($arg)*);
+    };
+}
+
+#[macro_export]
+macro_rules! trace_once {
+    ($($arg:tt)*) => {
+        ris_log::log_once!(ris_log::log_level::LogLevel::Trace, $($arg)*);
+    };
+}
+
+#[macro_export]
+macro_rules! debug_once {
+    ($($arg:tt)*) => {
+        ris_log::log_once!(ris_log::log_level::LogLevel::Debug, $($arg)*);
+    };
+}
+
+#[macro_export]
+macro_rules! info_once {
+    ($($arg:tt)*) => {
+        ris_log::log_once!(ris_log::log_level::LogLevel::Info, $($arg)*);
+    };
+}
+
+#[macro_export]
+macro_rules! warning_once {
+    ($($arg:tt)*) => {
+        ris_log::log_once!(ris_log::log_level::LogLevel::Warning, $($arg)*);
+    };
+}
+
+#[macro_export]
+macro_rules! error_once {
+    ($($arg:tt)*) => {
+        ris_log::log_once!(ris_log::log_level::LogLevel::Error, $($arg)*);
+    };
+}
+
+#[macro_export]
+macro_rules! fatal_once {
+    ($($arg:tt)*) => {
+        ris_log::log_once!(ris_log::log_level::LogLevel::Fatal, $($arg)*);
+    };
+}
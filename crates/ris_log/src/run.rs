@@ -0,0 +1,84 @@
+use std::sync::atomic::AtomicU32;
+use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+const PREFIX: &str = "log run ";
+const INFIX: &str = " started at ";
+
+static INDEX: AtomicU32 = AtomicU32::new(0);
+static CURRENT: Mutex<Option<Run>> = Mutex::new(None);
+
+// every call to `log::init` starts a run, which numbers its messages from 0. log files are
+// appended to, so they mark where each run starts. the counter only orders messages within a run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Run {
+    pub process_id: u32,
+    // counts the runs of a process, starting at 1
+    pub index: u32,
+    pub started: SystemTime,
+}
+
+impl std::fmt::Display for Run {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}.{}{}{}",
+            PREFIX,
+            self.process_id,
+            self.index,
+            INFIX,
+            crate::timestamp::fmt_iso8601(self.started),
+        )
+    }
+}
+
+impl Run {
+    // the inverse of `Display`, i.e. `log run 1234.1 started at 2025-12-10T14:03:22.123Z`
+    pub fn parse(value: &str) -> Option<Self> {
+        let rest = value.trim().strip_prefix(PREFIX)?;
+        let (id, started) = rest.split_once(INFIX)?;
+        let (process_id, index) = id.split_once('.')?;
+
+        Some(Self {
+            process_id: process_id.parse().ok()?,
+            index: index.parse().ok()?,
+            started: crate::timestamp::parse_iso8601(started).ok()?,
+        })
+    }
+}
+
+// called by `log::init`
+#[cfg(feature = "logging_enabled")]
+pub(crate) fn start() {
+    let mut current = match CURRENT.lock() {
+        Ok(current) => current,
+        Err(e) => {
+            eprintln!("error while starting log run: {}", e);
+            return;
+        }
+    };
+
+    let index = INDEX.fetch_add(1, Ordering::Relaxed) + 1;
+    *current = Some(Run {
+        process_id: std::process::id(),
+        index,
+        started: SystemTime::now(),
+    });
+}
+
+// changes whenever a run starts. 0 if none has started yet. cheaper than `current`, such that
+// appenders can check for a new run on every message
+pub fn index() -> u32 {
+    INDEX.load(Ordering::Relaxed)
+}
+
+pub fn current() -> Option<Run> {
+    match CURRENT.lock() {
+        Ok(current) => *current,
+        Err(e) => {
+            eprintln!("error while getting log run: {}", e);
+            None
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use std::cell::RefCell;
+use std::time::Instant;
+use std::time::SystemTime;
+
+use crate::constructed_log_message::ConstructedLogMessage;
+use crate::field::Field;
+use crate::log_level::LogLevel;
+use crate::log_message::LogMessage;
+
+pub const SPAN_LEVEL: LogLevel = LogLevel::Info;
+
+thread_local! {
+    static SPANS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+// the names of all spans the current thread is in, from outermost to innermost
+pub fn current_path() -> Vec<String> {
+    SPANS.with(|x| x.borrow().clone())
+}
+
+// logs when it is entered and when it is dropped, including the elapsed time. spans are
+// per thread and must be dropped in the reverse order they were entered
+pub struct Span {
+    name: String,
+    package: &'static str,
+    file: &'static str,
+    line: u32,
+    depth: usize,
+    start: Instant,
+}
+
+impl Span {
+    pub fn enter(name: String, package: &'static str, file: &'static str, line: u32) -> Self {
+        let depth = SPANS.with(|x| x.borrow().len());
+
+        let span = Self {
+            name,
+            package,
+            file,
+            line,
+            depth,
+            start: Instant::now(),
+        };
+
+        span.log(format!("enter {}", span.name), Vec::new());
+        SPANS.with(|x| x.borrow_mut().push(span.name.clone()));
+
+        span
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn log(&self, message: String, fields: Vec<Field>) {
+        if !crate::log::enabled(SPAN_LEVEL, self.package, self.file) {
+            return;
+        }
+
+        let constructed_log = ConstructedLogMessage {
+            package: self.package.to_string(),
+            file: self.file.to_string(),
+            line: self.line,
+            timestamp: crate::log::get_timestamp(),
+            elapsed: crate::timestamp::elapsed(),
+            time: SystemTime::now(),
+            thread_id: crate::thread::id(),
+            thread_name: crate::thread::name(),
+            priority: SPAN_LEVEL,
+            message,
+            fields,
+            span_path: current_path(),
+        };
+
+        crate::log::forward_to_appenders(LogMessage::Constructed(constructed_log));
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        SPANS.with(|x| x.borrow_mut().truncate(self.depth));
+
+        let elapsed = self.start.elapsed();
+        let fields = vec![Field::new("elapsed", elapsed)];
+        self.log(format!("exit {}", self.name), fields);
+    }
+}
+
+// usage:
+//
+//     let _span = ris_log::span!("part {}", 1);
+#[macro_export]
+macro_rules! span {
+    ($($arg:tt)*) => {
+        ris_log::span::Span::enter(
+            format!($($arg)*),
+            env!("CARGO_PKG_NAME"),
+            file!(),
+            line!(),
+        )
+    };
+}
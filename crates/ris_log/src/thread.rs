@@ -0,0 +1,42 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    static ID: Cell<u64> = const { Cell::new(0) };
+    static NAME: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+// a number that identifies the current thread in log messages. ids are assigned in the order in
+// which threads first ask for one, and are never reused
+pub fn id() -> u64 {
+    ID.with(|x| {
+        if x.get() == 0 {
+            x.set(NEXT_ID.fetch_add(1, Ordering::Relaxed));
+        }
+
+        x.get()
+    })
+}
+
+// the name set by `set_name`, or the name the thread was spawned with
+pub fn name() -> Option<String> {
+    let name = NAME.with(|x| x.borrow().clone());
+    name.or_else(|| std::thread::current().name().map(String::from))
+}
+
+// names the current thread in log messages. useful for threads that were spawned without a name,
+// i.e. by `std::thread::scope`
+pub fn set_name(name: impl Into<String>) {
+    NAME.with(|x| *x.borrow_mut() = Some(name.into()));
+}
+
+pub fn fmt(id: u64, name: Option<&str>) -> String {
+    match name {
+        Some(name) => format!("#{} {}", id, name),
+        None => format!("#{}", id),
+    }
+}
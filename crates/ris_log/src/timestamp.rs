@@ -0,0 +1,307 @@
+use std::sync::OnceLock;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+
+static START: OnceLock<Instant> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampFormat {
+    // the sequence number of the message, i.e. `[42]`
+    Counter,
+    // milliseconds since the log was initialized, i.e. `[1234.567ms]`
+    RelativeMs,
+    // the wall clock time in utc, i.e. `[2025-12-10T14:03:22.123Z]`
+    Iso8601,
+}
+
+// starts the clock, which `elapsed` is measured against. only the first call has an effect
+pub fn start() {
+    START.get_or_init(Instant::now);
+}
+
+// the time since the log was initialized
+pub fn elapsed() -> Duration {
+    START.get_or_init(Instant::now).elapsed()
+}
+
+pub fn fmt_relative_ms(elapsed: Duration) -> String {
+    format!("{:.3}ms", elapsed.as_secs_f64() * 1000.0)
+}
+
+pub fn fmt_iso8601(time: SystemTime) -> String {
+    // times before the epoch are not expected. they are clamped to the epoch
+    let since_epoch = time
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default();
+
+    let secs = since_epoch.as_secs();
+    let millis = since_epoch.subsec_millis();
+
+    let days = secs / 86400;
+    let seconds_of_day = secs % 86400;
+    let hour = seconds_of_day / 3600;
+    let minute = seconds_of_day % 3600 / 60;
+    let second = seconds_of_day % 60;
+
+    let (year, month, day) = civil_from_days(days as i64);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year, month, day, hour, minute, second, millis,
+    )
+}
+
+// the inverse of `fmt_iso8601`. the fraction, the time and the `Z` may be omitted, i.e.
+// `2025-12-10T14:03:22Z` or `2025-12-10`. times are always interpreted as utc
+pub fn parse_iso8601(value: &str) -> Result<SystemTime, String> {
+    let invalid = || format!("invalid time: {}", value);
+
+    let trimmed = value.trim();
+    let trimmed = trimmed.strip_suffix('Z').unwrap_or(trimmed);
+    let (date, time) = match trimmed.split_once(['T', ' ']) {
+        Some((date, time)) => (date, Some(time)),
+        None => (trimmed, None),
+    };
+
+    let mut date = date.splitn(3, '-');
+    let mut next_date = || date.next().ok_or_else(invalid);
+    let year = next_date()?.parse::<i64>().map_err(|_| invalid())?;
+    let month = next_date()?.parse::<u32>().map_err(|_| invalid())?;
+    let day = next_date()?.parse::<u32>().map_err(|_| invalid())?;
+    if !(1..=12).contains(&month) {
+        return Err(format!("month {} is out of range in {}", month, value));
+    }
+    if !(1..=days_in_month(year, month)).contains(&day) {
+        return Err(format!("day {} is out of range in {}", day, value));
+    }
+
+    let mut since_epoch = Duration::ZERO;
+    if let Some(time) = time {
+        let (time, fraction) = match time.split_once('.') {
+            Some((time, fraction)) => (time, Some(fraction)),
+            None => (time, None),
+        };
+
+        let mut time = time.splitn(3, ':');
+        let mut next_time = || match time.next() {
+            Some(x) => x.parse::<u64>().map(Some).map_err(|_| invalid()),
+            None => Ok(None),
+        };
+        let hour = next_time()?.ok_or_else(invalid)?;
+        let minute = next_time()?.ok_or_else(invalid)?;
+        let second = next_time()?.unwrap_or(0);
+        if hour > 23 || minute > 59 || second > 59 {
+            return Err(format!("time of day is out of range in {}", value));
+        }
+        since_epoch += Duration::from_secs(hour * 3600 + minute * 60 + second);
+
+        if let Some(fraction) = fraction {
+            if fraction.is_empty() || !fraction.chars().all(|x| x.is_ascii_digit()) {
+                return Err(invalid());
+            }
+
+            let fraction = format!("0.{}", fraction)
+                .parse::<f64>()
+                .map_err(|_| invalid())?;
+            since_epoch += Duration::from_secs_f64(fraction);
+        }
+    }
+
+    let days = days_from_civil(year, month, day);
+    if days < 0 {
+        return Err(format!("{} is before 1970", value));
+    }
+    since_epoch += Duration::from_secs(days as u64 * 86400);
+
+    Ok(SystemTime::UNIX_EPOCH + since_epoch)
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// the inverse of `civil_from_days`
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let shifted_month = if month > 2 { month - 3 } else { month + 9 } as i64;
+    let day_of_year = (153 * shifted_month + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + day_of_era - 719468
+}
+
+// converts days since 1970-01-01 into a date of the proleptic gregorian calendar. see
+// http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn civil_from_days_starts_at_the_epoch() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+    }
+
+    #[test]
+    fn civil_from_days_handles_leap_days() {
+        let leap_day = days_from_civil(2024, 2, 29);
+        assert_eq!(civil_from_days(leap_day), (2024, 2, 29));
+        assert_eq!(civil_from_days(leap_day + 1), (2024, 3, 1));
+
+        // divisible by 400
+        let leap_day = days_from_civil(2000, 2, 29);
+        assert_eq!(civil_from_days(leap_day), (2000, 2, 29));
+
+        // divisible by 100, but not by 400
+        let last_of_february = days_from_civil(2100, 2, 28);
+        assert_eq!(civil_from_days(last_of_february + 1), (2100, 3, 1));
+    }
+
+    #[test]
+    fn civil_from_days_handles_month_ends() {
+        for (year, month) in [(2025, 1), (2025, 4), (2025, 11), (2025, 12), (2023, 2)] {
+            let last = days_from_civil(year, month, days_in_month(year, month));
+            let (next_year, next_month) = if month == 12 {
+                (year + 1, 1)
+            } else {
+                (year, month + 1)
+            };
+
+            assert_eq!(
+                civil_from_days(last),
+                (year, month, days_in_month(year, month))
+            );
+            assert_eq!(civil_from_days(last + 1), (next_year, next_month, 1));
+        }
+    }
+
+    #[test]
+    fn civil_from_days_is_the_inverse_of_days_from_civil() {
+        // from 1900 to 2100, which covers both kinds of century years
+        for days in -25567..47482 {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn is_leap_year_follows_the_gregorian_calendar() {
+        assert!(is_leap_year(2024));
+        assert!(is_leap_year(2000));
+        assert!(!is_leap_year(2025));
+        assert!(!is_leap_year(1900));
+    }
+
+    #[test]
+    fn parse_iso8601_is_the_inverse_of_fmt_iso8601() {
+        let time = SystemTime::UNIX_EPOCH + Duration::from_millis(1_765_375_402_123);
+        let formatted = fmt_iso8601(time);
+        assert_eq!(formatted, "2025-12-10T14:03:22.123Z");
+        assert_eq!(parse_iso8601(&formatted), Ok(time));
+    }
+
+    #[test]
+    fn parse_iso8601_allows_to_omit_parts() {
+        let date = SystemTime::UNIX_EPOCH + Duration::from_secs(1_765_324_800);
+        assert_eq!(parse_iso8601("2025-12-10"), Ok(date));
+        assert_eq!(
+            parse_iso8601("2025-12-10T14:03"),
+            Ok(date + Duration::from_secs(14 * 3600 + 3 * 60))
+        );
+        assert_eq!(
+            parse_iso8601("2025-12-10 14:03:22Z"),
+            Ok(date + Duration::from_secs(14 * 3600 + 3 * 60 + 22))
+        );
+    }
+
+    #[test]
+    fn parse_iso8601_accepts_edge_dates() {
+        assert_eq!(parse_iso8601("1970-01-01"), Ok(SystemTime::UNIX_EPOCH));
+        assert!(parse_iso8601("2024-02-29").is_ok());
+        assert!(parse_iso8601("2000-02-29").is_ok());
+        assert!(parse_iso8601("2025-12-31T23:59:59.999").is_ok());
+    }
+
+    #[test]
+    fn parse_iso8601_rejects_dates_out_of_range() {
+        let invalid = [
+            "2025-02-29",
+            "2100-02-29",
+            "2025-04-31",
+            "2025-02-31",
+            "2025-00-10",
+            "2025-13-10",
+            "2025-12-00",
+            "1969-12-31",
+        ];
+
+        for value in invalid {
+            assert!(parse_iso8601(value).is_err(), "{}", value);
+        }
+    }
+
+    #[test]
+    fn parse_iso8601_rejects_times_out_of_range() {
+        let invalid = [
+            "2025-12-10T24:00",
+            "2025-12-10T12:60",
+            "2025-12-10T12:00:60",
+            "2025-12-10T25:99:99",
+        ];
+
+        for value in invalid {
+            let error = parse_iso8601(value).unwrap_err();
+            assert!(error.contains("out of range"), "{}", error);
+        }
+    }
+
+    #[test]
+    fn parse_iso8601_rejects_malformed_times() {
+        let invalid = [
+            "",
+            "2025",
+            "2025-12",
+            "2025-12-1x",
+            "2025-12-10T",
+            "2025-12-10T14",
+            "2025-12-10T14:03:22.",
+            "2025-12-10T14:03:22.1e3",
+            "2025-12-10T14:03:22.-1",
+        ];
+
+        for value in invalid {
+            assert!(parse_iso8601(value).is_err(), "{:?}", value);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+pub mod day_1;
+pub mod day_10;
+pub mod day_2;
+pub mod day_3;
+pub mod day_4;
+pub mod day_5;
+pub mod day_6;
+pub mod day_7;
+pub mod day_8;
+pub mod day_9;
+
+use crate::Solver;
+
+pub const SOLVERS: &[Solver] = &[
+    day_1::run,
+    day_2::run,
+    day_3::run,
+    day_4::run,
+    day_5::run,
+    day_6::run,
+    day_7::run,
+    day_8::run,
+    day_9::run,
+    day_10::run,
+];
@@ -1,12 +1,23 @@
 use ris_error::prelude::*;
 
-const PUZZLE_INPUT_KEY: &str = "day_1";
+pub fn run(input: &str, answer: &mut crate::Answer) -> RisResult<()> {
+    ris_log::info!("parse input...");
+    let rotations = parse(input)?;
 
-pub fn run(answer: &mut crate::Answer) -> RisResult<()> {
-    ris_log::info!("read input...");
-    let input = crate::read_puzzle_input(PUZZLE_INPUT_KEY)?;
+    let span = ris_log::span!("part 1");
+    let result = run_part_1(&rotations)?;
+    answer.add(format!("1: {}", result));
+    drop(span);
 
-    ris_log::info!("parse input...");
+    let span = ris_log::span!("part 2");
+    let result = run_part_2(&rotations)?;
+    answer.add(format!("2: {}", result));
+    drop(span);
+
+    Ok(())
+}
+
+pub fn parse(input: &str) -> RisResult<Vec<Rotation>> {
     let mut rotations = Vec::new();
     for line in input.lines() {
         if line.is_empty() {
@@ -17,18 +28,10 @@ pub fn run(answer: &mut crate::Answer) -> RisResult<()> {
         rotations.push(rotation);
     }
 
-    ris_log::info!("run part 1...");
-    let result = run_part_1(&rotations)?;
-    answer.add(format!("1: {}", result));
-
-    ris_log::info!("run part 2...");
-    let result = run_part_2(&rotations)?;
-    answer.add(format!("2: {}", result));
-
-    Ok(())
+    Ok(rotations)
 }
 
-fn run_part_1(input: &[Rotation]) -> RisResult<usize> {
+pub fn run_part_1(input: &[Rotation]) -> RisResult<usize> {
     ris_log::info!("apply rotations...");
     let mut dial = Dial::<100> { position: 50 };
 
@@ -45,7 +48,7 @@ fn run_part_1(input: &[Rotation]) -> RisResult<usize> {
     Ok(counter)
 }
 
-fn run_part_2(input: &[Rotation]) -> RisResult<usize> {
+pub fn run_part_2(input: &[Rotation]) -> RisResult<usize> {
     ris_log::info!("apply rotations...");
     let mut dial = Dial::<100> { position: 50 };
 
@@ -72,15 +75,15 @@ fn run_part_2(input: &[Rotation]) -> RisResult<usize> {
 }
 
 #[derive(Debug, Clone, Copy)]
-enum Direction {
+pub enum Direction {
     Left,
     Right,
 }
 
 #[derive(Debug, Clone, Copy)]
-struct Rotation {
-    direction: Direction,
-    clicks: usize,
+pub struct Rotation {
+    pub direction: Direction,
+    pub clicks: usize,
 }
 
 #[derive(Debug, Clone, Copy)]
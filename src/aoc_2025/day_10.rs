@@ -1,12 +1,23 @@
 use ris_error::prelude::*;
 
-const PUZZLE_INPUT_KEY: &str = "day_10";
+pub fn run(input: &str, answer: &mut crate::Answer) -> RisResult<()> {
+    ris_log::info!("parse_inputs...");
+    let machines = parse(input)?;
 
-pub fn run(answer: &mut crate::Answer) -> RisResult<()> {
-    ris_log::info!("read input...");
-    let input = crate::read_puzzle_input(PUZZLE_INPUT_KEY)?;
+    let span = ris_log::span!("part 1");
+    let result = run_part_1(&machines)?;
+    answer.add(format!("1: {}", result));
+    drop(span);
 
-    ris_log::info!("parse_inputs...");
+    let span = ris_log::span!("part 2");
+    let result = run_part_2(&machines)?;
+    answer.add(format!("2: {}", result));
+    drop(span);
+
+    Ok(())
+}
+
+pub fn parse(input: &str) -> RisResult<Vec<Machine>> {
     let mut machines = Vec::new();
     for line in input.lines() {
         let line = line.trim();
@@ -69,18 +80,10 @@ pub fn run(answer: &mut crate::Answer) -> RisResult<()> {
         machines.push(machine);
     }
 
-    ris_log::info!("run part 1...");
-    let result = run_part_1(&machines)?;
-    answer.add(format!("1: {}", result));
-
-    ris_log::info!("run part 2...");
-    let result = run_part_2(&machines)?;
-    answer.add(format!("2: {}", result));
-
-    Ok(())
+    Ok(machines)
 }
 
-fn run_part_1(machines: &[Machine]) -> RisResult<usize> {
+pub fn run_part_1(machines: &[Machine]) -> RisResult<usize> {
     let mut sum = 0;
 
     for machine in machines.iter() {
@@ -122,36 +125,39 @@ fn run_part_1(machines: &[Machine]) -> RisResult<usize> {
     Ok(sum)
 }
 
-fn run_part_2(machines: &[Machine]) -> RisResult<usize> {
+pub fn run_part_2(machines: &[Machine]) -> RisResult<usize> {
     use std::sync::atomic::AtomicUsize;
     use std::sync::atomic::Ordering;
     use std::sync::Arc;
 
+    let progress = ris_log::progress!("run machines", machines.len());
+
     std::thread::scope(|s| {
         let sum = Arc::new(AtomicUsize::new(0));
         let num_threads = 12;
-        let progress = Arc::new(AtomicUsize::new(0));
 
+        let mut handles = Vec::with_capacity(num_threads);
         for i in 0..num_threads {
             let sum = sum.clone();
-            let progress = progress.clone();
-            s.spawn(move || {
-                for machine in machines.iter().skip(i).step_by(num_threads) {
-                    // log progress
-                    let progress = progress.fetch_add(1, Ordering::Relaxed);
-                    let percentage = 100.0 * progress as f32 / machines.len() as f32;
-                    ris_log::info!(
-                        "run machine... {}/{} {}%",
-                        progress,
-                        machines.len(),
-                        percentage,
-                    );
-
-                    // run machine
-                    let steps = configure_machine(machine);
-                    sum.fetch_add(steps, Ordering::Relaxed);
-                }
-            });
+            let progress = &progress;
+            let handle = std::thread::Builder::new()
+                .name(format!("machine worker {}", i))
+                .spawn_scoped(s, move || {
+                    for machine in machines.iter().skip(i).step_by(num_threads) {
+                        let steps = configure_machine(machine);
+                        sum.fetch_add(steps, Ordering::Relaxed);
+                        progress.inc();
+                    }
+                })?;
+            handles.push(handle);
+        }
+
+        // the scope only joins its threads after this closure returns. the sum is complete once
+        // every worker is joined
+        for handle in handles {
+            if let Err(payload) = handle.join() {
+                std::panic::resume_unwind(payload);
+            }
         }
 
         let result = sum.load(Ordering::Relaxed);
@@ -159,16 +165,15 @@ fn run_part_2(machines: &[Machine]) -> RisResult<usize> {
     })
 }
 
-
-type Lights = Vec<bool>;
-type Button = Vec<usize>;
-type Joltages = Vec<usize>;
+pub type Lights = Vec<bool>;
+pub type Button = Vec<usize>;
+pub type Joltages = Vec<usize>;
 
 #[derive(Debug)]
-struct Machine {
-    lights: Lights,
-    buttons: Vec<Button>,
-    joltages: Joltages,
+pub struct Machine {
+    pub lights: Lights,
+    pub buttons: Vec<Button>,
+    pub joltages: Joltages,
 }
 
 fn press_button_1(button: &Button, lights: &Lights) -> Lights {
@@ -222,11 +227,7 @@ fn configure_machine(machine: &Machine) -> usize {
             single_button_found = true;
 
             let button = &entry[0];
-            loop {
-                let Some(new_joltage) = press_button_2(button, &joltage) else {
-                    break;
-                };
-
+            while let Some(new_joltage) = press_button_2(button, &joltage) {
                 joltage = new_joltage;
                 generation += 1;
             }
@@ -313,7 +314,7 @@ fn remove_button(buttons: &mut Vec<Button>, button: &Button) -> bool {
             }
         }
 
-        return true;
+        true
     });
 
     // button_index is None if it was already removed. this may happen if the same button
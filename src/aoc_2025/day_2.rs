@@ -1,12 +1,23 @@
 use ris_error::prelude::*;
 
-const PUZZLE_INPUT_KEY: &str = "day_2";
+pub fn run(input: &str, answer: &mut crate::Answer) -> RisResult<()> {
+    ris_log::info!("parse input...");
+    let ranges = parse(input)?;
 
-pub fn run(answer: &mut crate::Answer) -> RisResult<()> {
-    ris_log::info!("read input...");
-    let input = crate::read_puzzle_input(PUZZLE_INPUT_KEY)?;
+    let span = ris_log::span!("part 1");
+    let result = run_part_1(&ranges);
+    answer.add(format!("1: {}", result));
+    drop(span);
 
-    ris_log::info!("parse input...");
+    let span = ris_log::span!("part 2");
+    let result = run_part_2(&ranges);
+    answer.add(format!("2: {}", result));
+    drop(span);
+
+    Ok(())
+}
+
+pub fn parse(input: &str) -> RisResult<Vec<std::ops::Range<usize>>> {
     let mut ranges = Vec::new();
     for split in input.split(',') {
         if split.is_empty() {
@@ -23,18 +34,10 @@ pub fn run(answer: &mut crate::Answer) -> RisResult<()> {
         ranges.push(range);
     }
 
-    ris_log::info!("run part 1...");
-    let result = run_part_1(&ranges);
-    answer.add(format!("1: {}", result));
-
-    ris_log::info!("run part 2...");
-    let result = run_part_2(&ranges);
-    answer.add(format!("2: {}", result));
-
-    Ok(())
+    Ok(ranges)
 }
 
-fn run_part_1(input: &[std::ops::Range<usize>]) -> usize {
+pub fn run_part_1(input: &[std::ops::Range<usize>]) -> usize {
     let mut sum = 0;
     for range in input.iter() {
         for n in range.clone() {
@@ -47,7 +50,7 @@ fn run_part_1(input: &[std::ops::Range<usize>]) -> usize {
     sum
 }
 
-fn run_part_2(input: &[std::ops::Range<usize>]) -> usize {
+pub fn run_part_2(input: &[std::ops::Range<usize>]) -> usize {
     let mut sum = 0;
     for range in input.iter() {
         for n in range.clone() {
@@ -1,12 +1,23 @@
 use ris_error::prelude::*;
 
-const PUZZLE_INPUT_KEY: &str = "day_3";
+pub fn run(input: &str, answer: &mut crate::Answer) -> RisResult<()> {
+    ris_log::info!("parse input...");
+    let banks = parse(input)?;
 
-pub fn run(answer: &mut crate::Answer) -> RisResult<()> {
-    ris_log::info!("read input...");
-    let input = crate::read_puzzle_input(PUZZLE_INPUT_KEY)?;
+    let span = ris_log::span!("part 1");
+    let result = run_part_1(&banks)?;
+    answer.add(format!("1: {}", result));
+    drop(span);
 
-    ris_log::info!("parse input...");
+    let span = ris_log::span!("part 2");
+    let result = run_part_2(&banks)?;
+    answer.add(format!("2: {}", result));
+    drop(span);
+
+    Ok(())
+}
+
+pub fn parse(input: &str) -> RisResult<Vec<Vec<usize>>> {
     let mut banks = Vec::new();
     for line in input.lines() {
         if line.is_empty() {
@@ -34,18 +45,10 @@ pub fn run(answer: &mut crate::Answer) -> RisResult<()> {
         banks.push(bank);
     }
 
-    ris_log::info!("run part 1...");
-    let result = run_part_1(&banks)?;
-    answer.add(format!("1: {}", result));
-
-    ris_log::info!("run part 2...");
-    let result = run_part_2(&banks)?;
-    answer.add(format!("2: {}", result));
-
-    Ok(())
+    Ok(banks)
 }
 
-fn run_part_1(banks: &[Vec<usize>]) -> RisResult<usize> {
+pub fn run_part_1(banks: &[Vec<usize>]) -> RisResult<usize> {
     let mut sum = 0;
 
     for bank in banks.iter() {
@@ -71,7 +74,7 @@ fn run_part_1(banks: &[Vec<usize>]) -> RisResult<usize> {
     Ok(sum)
 }
 
-fn run_part_2(banks: &[Vec<usize>]) -> RisResult<usize> {
+pub fn run_part_2(banks: &[Vec<usize>]) -> RisResult<usize> {
     let mut sum = 0;
 
     for bank in banks.iter() {
@@ -1,15 +1,26 @@
 use ris_error::prelude::*;
 
-const PUZZLE_INPUT_KEY: &str = "day_4";
-
 const ROLL: char = '@';
 const EMPTY: char = '.';
 
-pub fn run(answer: &mut crate::Answer) -> RisResult<()> {
-    ris_log::info!("read input...");
-    let input = crate::read_puzzle_input(PUZZLE_INPUT_KEY)?;
-
+pub fn run(input: &str, answer: &mut crate::Answer) -> RisResult<()> {
     ris_log::info!("parse input...");
+    let mut shelf = parse(input)?;
+
+    let span = ris_log::span!("part 1");
+    let result = run_part_1(&shelf);
+    answer.add(format!("1: {}", result));
+    drop(span);
+
+    let span = ris_log::span!("part 2");
+    let result = run_part_2(&mut shelf);
+    answer.add(format!("2: {}", result));
+    drop(span);
+
+    Ok(())
+}
+
+pub fn parse(input: &str) -> RisResult<Vec<Vec<Entry>>> {
     let mut shelf = Vec::new();
     for line in input.lines() {
         if line.is_empty() {
@@ -29,18 +40,10 @@ pub fn run(answer: &mut crate::Answer) -> RisResult<()> {
         shelf.push(entries);
     }
 
-    ris_log::info!("run part 1...");
-    let result = run_part_1(&shelf);
-    answer.add(format!("1: {}", result));
-
-    ris_log::info!("run part 2...");
-    let result = run_part_2(&mut shelf);
-    answer.add(format!("2: {}", result));
-
-    Ok(())
+    Ok(shelf)
 }
 
-fn run_part_1(shelf: &[Vec<Entry>]) -> usize {
+pub fn run_part_1(shelf: &[Vec<Entry>]) -> usize {
     let mut sum = 0;
     for (iy, entries) in shelf.iter().enumerate() {
         for (ix, &entry) in entries.iter().enumerate() {
@@ -57,7 +60,7 @@ fn run_part_1(shelf: &[Vec<Entry>]) -> usize {
     sum
 }
 
-fn run_part_2(shelf: &mut [Vec<Entry>]) -> usize {
+pub fn run_part_2(shelf: &mut [Vec<Entry>]) -> usize {
     let mut sum = 0;
 
     loop {
@@ -125,7 +128,7 @@ fn can_access(shelf: &[Vec<Entry>], ix: usize, iy: usize) -> bool {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Entry {
+pub enum Entry {
     Roll,
     Empty,
 }
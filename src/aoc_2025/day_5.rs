@@ -1,11 +1,28 @@
 use ris_error::prelude::*;
 
-const PUZZLE_INPUT_KEY: &str = "day_5";
+pub fn run(input: &str, answer: &mut crate::Answer) -> RisResult<()> {
+    let Database { id_ranges, mut ids } = parse(input)?;
 
-pub fn run(answer: &mut crate::Answer) -> RisResult<()> {
-    ris_log::info!("read input...");
-    let input = crate::read_puzzle_input(PUZZLE_INPUT_KEY)?;
+    ris_log::info!("sort ids...");
+    ids.sort();
 
+    ris_log::info!("resolve overlaps and merge ranges...");
+    let merged_id_ranges = merge_ranges(id_ranges);
+
+    let span = ris_log::span!("part 1");
+    let result = run_part_1(&merged_id_ranges, &ids)?;
+    answer.add(format!("1: {}", result));
+    drop(span);
+
+    let span = ris_log::span!("part 2");
+    let result = run_part_2(&merged_id_ranges);
+    answer.add(format!("2: {}", result));
+    drop(span);
+
+    Ok(())
+}
+
+pub fn parse(input: &str) -> RisResult<Database> {
     ris_log::info!("parse ranges...");
     let mut lines = input.lines();
 
@@ -17,7 +34,7 @@ pub fn run(answer: &mut crate::Answer) -> RisResult<()> {
         let min = min_str.parse()?;
         let max = max_str.parse()?;
         let id_range = IdRange { min, max };
-        id_ranges.push(Some(id_range));
+        id_ranges.push(id_range);
     }
 
     ris_log::info!("parse ids...");
@@ -31,6 +48,13 @@ pub fn run(answer: &mut crate::Answer) -> RisResult<()> {
         ids.push(id);
     }
 
+    Ok(Database { id_ranges, ids })
+}
+
+// returns sorted ranges, that do not overlap
+pub fn merge_ranges(id_ranges: Vec<IdRange>) -> Vec<IdRange> {
+    let mut id_ranges = id_ranges.into_iter().map(Some).collect::<Vec<_>>();
+
     ris_log::info!("sort ranges...");
     id_ranges.sort_by(|lhs, rhs| {
         let lhs = lhs.expect("no None element to be present");
@@ -38,10 +62,6 @@ pub fn run(answer: &mut crate::Answer) -> RisResult<()> {
         lhs.min.cmp(&rhs.min)
     });
 
-    ris_log::info!("sort ids...");
-    ids.sort();
-
-    ris_log::info!("resolve overlaps and merge ranges...");
     let mut merged_id_ranges = Vec::new();
     let mut i = 0;
     loop {
@@ -69,28 +89,17 @@ pub fn run(answer: &mut crate::Answer) -> RisResult<()> {
         merged_id_ranges.push(merged_id_range);
     }
 
-    ris_log::info!("run part 1...");
-    let result = run_part_1(&merged_id_ranges, &ids)?;
-    answer.add(format!("1: {}", result));
-
-    ris_log::info!("run part 2...");
-    let result = run_part_2(&merged_id_ranges);
-    answer.add(format!("2: {}", result));
-
-    Ok(())
+    merged_id_ranges
 }
 
-fn run_part_1(id_ranges: &[IdRange], ids: &[usize]) -> RisResult<usize> {
+// expects `id_ranges` to be merged and `ids` to be sorted
+pub fn run_part_1(id_ranges: &[IdRange], ids: &[usize]) -> RisResult<usize> {
     let mut sum = 0;
 
     let mut i = 0;
     let mut j = 0;
 
-    loop {
-        let Some(&id) = ids.get(i) else {
-            break;
-        };
-
+    while let Some(&id) = ids.get(i) {
         let Some(&range) = id_ranges.get(j) else {
             break;
         };
@@ -111,7 +120,7 @@ fn run_part_1(id_ranges: &[IdRange], ids: &[usize]) -> RisResult<usize> {
     Ok(sum)
 }
 
-fn run_part_2(id_ranges: &[IdRange]) -> usize {
+pub fn run_part_2(id_ranges: &[IdRange]) -> usize {
     let mut sum = 0;
 
     for range in id_ranges.iter() {
@@ -122,8 +131,14 @@ fn run_part_2(id_ranges: &[IdRange]) -> usize {
     sum
 }
 
+#[derive(Debug, Clone)]
+pub struct Database {
+    pub id_ranges: Vec<IdRange>,
+    pub ids: Vec<usize>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct IdRange {
-    min: usize,
-    max: usize,
+pub struct IdRange {
+    pub min: usize,
+    pub max: usize,
 }
@@ -1,23 +1,42 @@
 use ris_error::prelude::*;
 
-const PUZZLE_INPUT_KEY: &str = "day_6";
-
-pub fn run(answer: &mut crate::Answer) -> RisResult<()> {
-    ris_log::info!("read input...");
-    let input = crate::read_puzzle_input(PUZZLE_INPUT_KEY)?;
-
-    ris_log::info!("run part 1...");
-    let result = run_part_1(&input)?;
+pub fn run(input: &str, answer: &mut crate::Answer) -> RisResult<()> {
+    let span = ris_log::span!("part 1");
+    let result = run_part_1(input)?;
     answer.add(format!("1: {}", result));
+    drop(span);
 
-    ris_log::info!("run part 2...");
-    let result = run_part_2(&input)?;
+    let span = ris_log::span!("part 2");
+    let result = run_part_2(input)?;
     answer.add(format!("2: {}", result));
+    drop(span);
 
     Ok(())
 }
 
-fn run_part_1(input: &str) -> RisResult<usize> {
+pub fn run_part_1(input: &str) -> RisResult<usize> {
+    let problems = parse_part_1(input)?;
+    Ok(solve(&problems))
+}
+
+pub fn run_part_2(input: &str) -> RisResult<usize> {
+    let problems = parse_part_2(input)?;
+    Ok(solve(&problems))
+}
+
+fn solve(problems: &[Problem]) -> usize {
+    ris_log::info!("solve problems...");
+    let mut sum = 0;
+    for problem in problems {
+        let result = problem.solve();
+        sum += result
+    }
+
+    sum
+}
+
+// numbers are written in rows
+fn parse_part_1(input: &str) -> RisResult<Vec<Problem>> {
     ris_log::info!("parse input...");
     let mut lines = input.lines().collect::<Vec<_>>();
     let last = lines.len() - 1;
@@ -76,17 +95,11 @@ fn run_part_1(input: &str) -> RisResult<usize> {
         }
     }
 
-    ris_log::info!("solve problems...");
-    let mut sum = 0;
-    for problem in problems {
-        let result = problem.solve();
-        sum += result
-    }
-
-    Ok(sum)
+    Ok(problems)
 }
 
-fn run_part_2(input: &str) -> RisResult<usize> {
+// numbers are written in columns
+fn parse_part_2(input: &str) -> RisResult<Vec<Problem>> {
     ris_log::info!("read character matrix...");
     let mut m = Vec::new();
     for line in input.lines() {
@@ -102,12 +115,12 @@ fn run_part_2(input: &str) -> RisResult<usize> {
     let height = m.len();
 
     let mut t = Vec::new();
+    #[allow(clippy::needless_range_loop)]
+    // justification: since we are indexing a matrix,
+    // having both indices written out like this makes
+    // the code much clearer
     for ix in 0..width {
         let mut v = Vec::new();
-        #[allow(clippy::needless_range_loop)]
-        // justification: since we are indexing a matrix,
-        // having both indices written out like this makes
-        // the code much clearer
         for iy in 0..height {
             let c = m[iy][ix];
             v.push(c);
@@ -158,14 +171,7 @@ fn run_part_2(input: &str) -> RisResult<usize> {
         problem.numbers.push(number);
     }
 
-    ris_log::info!("solve problems...");
-    let mut sum = 0;
-    for problem in problems {
-        let result = problem.solve();
-        sum += result
-    }
-
-    Ok(sum)
+    Ok(problems)
 }
 
 #[derive(Debug, Clone, Copy)]
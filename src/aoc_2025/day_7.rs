@@ -1,12 +1,24 @@
 use ris_error::prelude::*;
 
-const PUZZLE_INPUT_KEY: &str = "day_7";
+pub fn run(input: &str, answer: &mut crate::Answer) -> RisResult<()> {
+    ris_log::info!("parse input...");
+    let (manifold, start) = parse(input)?;
 
-pub fn run(answer: &mut crate::Answer) -> RisResult<()> {
-    ris_log::info!("read input...");
-    let input = crate::read_puzzle_input(PUZZLE_INPUT_KEY)?;
+    let span = ris_log::span!("part 1");
+    let result = run_part_1(&manifold, start);
+    answer.add(format!("1: {}", result));
+    drop(span);
 
-    ris_log::info!("parse input...");
+    let span = ris_log::span!("part 2");
+    let result = run_part_2(&manifold, start);
+    answer.add(format!("2: {}", result));
+    drop(span);
+
+    Ok(())
+}
+
+// returns the rows of the manifold that contain splitters, and the column where the beam starts
+pub fn parse(input: &str) -> RisResult<(Vec<Vec<bool>>, usize)> {
     let mut lines = input.lines();
 
     ris_log::info!("parse start...");
@@ -38,18 +50,10 @@ pub fn run(answer: &mut crate::Answer) -> RisResult<()> {
         }
     }
 
-    ris_log::info!("run part 1...");
-    let result = run_part_1(&manifold, start);
-    answer.add(format!("1: {}", result));
-
-    ris_log::info!("run part 2...");
-    let result = run_part_2(&manifold, start);
-    answer.add(format!("2: {}", result));
-
-    Ok(())
+    Ok((manifold, start))
 }
 
-fn run_part_1(manifold: &[Vec<bool>], start: usize) -> usize {
+pub fn run_part_1(manifold: &[Vec<bool>], start: usize) -> usize {
     let mut sum = 0;
 
     let width = manifold[0].len();
@@ -80,7 +84,7 @@ fn run_part_1(manifold: &[Vec<bool>], start: usize) -> usize {
     sum
 }
 
-fn run_part_2(manifold: &[Vec<bool>], start: usize) -> usize {
+pub fn run_part_2(manifold: &[Vec<bool>], start: usize) -> usize {
     let width = manifold[0].len();
     let mut state = vec![1; width];
 
@@ -2,48 +2,36 @@ use std::collections::HashSet;
 
 use ris_error::prelude::*;
 
-const PUZZLE_INPUT_KEY: &str = "day_8";
-const CONNECTIONS: usize = 1000;
-
-pub fn run(answer: &mut crate::Answer) -> RisResult<()> {
-    ris_log::info!("read input...");
-    let input = crate::read_puzzle_input(PUZZLE_INPUT_KEY)?;
+pub const CONNECTIONS: usize = 1000;
 
+pub fn run(input: &str, answer: &mut crate::Answer) -> RisResult<()> {
     ris_log::info!("parse input...");
-    let mut boxes = Vec::new();
-    for line in input.lines() {
-        let line = line.trim();
-        if line.is_empty() {
-            continue;
-        }
-
-        let mut splits = line.split(',');
-        let x_str = splits.next().into_ris_error()?;
-        let y_str = splits.next().into_ris_error()?;
-        let z_str = splits.next().into_ris_error()?;
+    let boxes = parse(input)?;
 
-        let x = x_str.parse()?;
-        let y = y_str.parse()?;
-        let z = z_str.parse()?;
+    ris_log::info!("compute connections...");
+    let connections = compute_connections(&boxes);
 
-        let p = Vec3(x, y, z);
-        boxes.push(p);
-    }
+    let span = ris_log::span!("part 1");
+    let result = run_part_1(&connections)?;
+    answer.add(format!("1: {}", result));
+    drop(span);
 
-    ris_log::info!("compute connections...");
-    let mut all_possible_connections = Vec::new();
-    for (i, &a) in boxes.iter().enumerate() {
-        for &b in boxes.iter().skip(i + 1) {
-            let connection = Connection::new(a, b);
-            all_possible_connections.push(connection);
-        }
-    }
+    let span = ris_log::span!("part 2");
+    let result = match run_part_2(&boxes, &connections) {
+        Some(result) => result.to_string(),
+        None => "None".to_string(),
+    };
+    answer.add(format!("2: {}", result));
+    drop(span);
 
-    ris_log::info!("sort connections...");
-    all_possible_connections.sort_by(|lhs, rhs| lhs.squared_magnitude.cmp(&rhs.squared_magnitude));
+    Ok(())
+}
 
+// the product of the sizes of the three largest circuits, after making the `CONNECTIONS`
+// shortest connections
+pub fn run_part_1(connections: &[Connection]) -> RisResult<usize> {
     ris_log::info!("build circuits...");
-    let mut connection_iter = all_possible_connections.iter();
+    let mut connection_iter = connections.iter();
     let mut circuits: Vec<HashSet<Vec3>> = Vec::new();
     for _ in 0..CONNECTIONS {
         let connection = *connection_iter.next().into_ris_error()?;
@@ -53,13 +41,16 @@ pub fn run(answer: &mut crate::Answer) -> RisResult<()> {
     ris_log::info!("sort circuits...");
     circuits.sort_by_key(|rhs| std::cmp::Reverse(rhs.len()));
 
-    ris_log::info!("compute answer 1...");
     let product = circuits.iter().map(|x| x.len()).take(3).product::<usize>();
-    answer.add(format!("1: {}", product));
+    Ok(product)
+}
 
-    ris_log::info!("continue building circuits...");
-    let mut product = None;
-    for &connection in connection_iter {
+// the product of the x coordinates of the two boxes, whose connection joins all boxes into a
+// single circuit. `None` if the connections never join all boxes
+pub fn run_part_2(boxes: &[Vec3], connections: &[Connection]) -> Option<isize> {
+    ris_log::info!("build circuits...");
+    let mut circuits: Vec<HashSet<Vec3>> = Vec::new();
+    for &connection in connections {
         connect(&mut circuits, connection);
 
         // consider breaking early
@@ -79,24 +70,57 @@ pub fn run(answer: &mut crate::Answer) -> RisResult<()> {
             b,
             squared_magnitude: _,
         } = connection;
-        product = Some(a.0 * b.0);
-        break;
+        return Some(a.0 * b.0);
     }
 
-    let product_string = match product {
-        Some(product) => product.to_string(),
-        None => "None".to_string(),
-    };
-    answer.add(format!("2: {}", product_string));
+    None
+}
 
-    Ok(())
+pub fn parse(input: &str) -> RisResult<Vec<Vec3>> {
+    let mut boxes = Vec::new();
+    for line in input.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut splits = line.split(',');
+        let x_str = splits.next().into_ris_error()?;
+        let y_str = splits.next().into_ris_error()?;
+        let z_str = splits.next().into_ris_error()?;
+
+        let x = x_str.parse()?;
+        let y = y_str.parse()?;
+        let z = z_str.parse()?;
+
+        let p = Vec3(x, y, z);
+        boxes.push(p);
+    }
+
+    Ok(boxes)
+}
+
+// returns all connections between any two boxes, sorted from shortest to longest
+pub fn compute_connections(boxes: &[Vec3]) -> Vec<Connection> {
+    let mut all_possible_connections = Vec::new();
+    for (i, &a) in boxes.iter().enumerate() {
+        for &b in boxes.iter().skip(i + 1) {
+            let connection = Connection::new(a, b);
+            all_possible_connections.push(connection);
+        }
+    }
+
+    ris_log::info!("sort connections...");
+    all_possible_connections.sort_by_key(|x| x.squared_magnitude);
+
+    all_possible_connections
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct Vec3(isize, isize, isize);
+pub struct Vec3(pub isize, pub isize, pub isize);
 
 #[derive(Debug, Clone, Copy)]
-struct Connection {
+pub struct Connection {
     a: Vec3,
     b: Vec3,
     squared_magnitude: usize,
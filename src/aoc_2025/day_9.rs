@@ -1,12 +1,23 @@
 use ris_error::prelude::*;
 
-const PUZZLE_INPUT_KEY: &str = "day_9";
+pub fn run(input: &str, answer: &mut crate::Answer) -> RisResult<()> {
+    ris_log::info!("parse_inputs...");
+    let tiles = parse(input)?;
 
-pub fn run(answer: &mut crate::Answer) -> RisResult<()> {
-    ris_log::info!("read input...");
-    let input = crate::read_puzzle_input(PUZZLE_INPUT_KEY)?;
+    let span = ris_log::span!("part 1");
+    let result = run_part_1(&tiles);
+    answer.add(format!("1: {}", result));
+    drop(span);
 
-    ris_log::info!("parse_inputs...");
+    let span = ris_log::span!("part 2");
+    let result = run_part_2(&tiles)?;
+    answer.add(format!("2: {}", result));
+    drop(span);
+
+    Ok(())
+}
+
+pub fn parse(input: &str) -> RisResult<Vec<Vec2>> {
     let mut tiles = Vec::new();
     for line in input.lines() {
         let line = line.trim();
@@ -23,18 +34,10 @@ pub fn run(answer: &mut crate::Answer) -> RisResult<()> {
         tiles.push(tile);
     }
 
-    ris_log::info!("run part 1...");
-    let result = run_part_1(&tiles);
-    answer.add(format!("1: {}", result));
-
-    ris_log::info!("run part 2...");
-    let result = run_part_2(&tiles)?;
-    answer.add(format!("2: {}", result));
-
-    Ok(())
+    Ok(tiles)
 }
 
-fn run_part_1(tiles: &[Vec2]) -> usize {
+pub fn run_part_1(tiles: &[Vec2]) -> usize {
     let mut max_area = usize::MIN;
 
     for (i, &a) in tiles.iter().enumerate() {
@@ -50,7 +53,7 @@ fn run_part_1(tiles: &[Vec2]) -> usize {
     max_area
 }
 
-fn run_part_2(tiles: &[Vec2]) -> RisResult<usize> {
+pub fn run_part_2(tiles: &[Vec2]) -> RisResult<usize> {
     // parse lines
     ris_log::info!("parse lines...");
     let mut vertical_lines = Vec::new();
@@ -149,16 +152,16 @@ fn run_part_2(tiles: &[Vec2]) -> RisResult<usize> {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Vec2(usize, usize);
+pub struct Vec2(pub usize, pub usize);
 
 #[derive(Debug, Clone, Copy)]
-struct Aabb {
-    min: Vec2,
-    max: Vec2,
+pub struct Aabb {
+    pub min: Vec2,
+    pub max: Vec2,
 }
 
 impl Aabb {
-    fn new(t1: Vec2, t2: Vec2) -> Self {
+    pub fn new(t1: Vec2, t2: Vec2) -> Self {
         let (min_x, max_x) = if t1.0 < t2.0 {
             (t1.0, t2.0)
         } else {
@@ -177,7 +180,7 @@ impl Aabb {
         }
     }
 
-    fn area(self) -> usize {
+    pub fn area(self) -> usize {
         let x = self.max.0 - self.min.0 + 1;
         let y = self.max.1 - self.min.1 + 1;
         x * y
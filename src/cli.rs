@@ -0,0 +1,230 @@
+use ris_log::log_format::LogFormat;
+use ris_log::log_reader::LogQuery;
+
+use crate::event;
+use crate::event::Puzzle;
+use crate::event::Selection;
+use crate::history;
+use crate::history::Feedback;
+use crate::history::Submission;
+
+pub const SUBCOMMANDS: &[&str] = &["shrink", "record", "history", "logs"];
+
+pub struct Args {
+    // in porcelain mode, stdout is meant to be read by a machine. only answers are printed and
+    // progress is not reported
+    pub porcelain: bool,
+    // repeated console lines are collapsed. they are held back until a different message is
+    // logged, which delays interactive output
+    pub log_dedup: bool,
+    pub trace_failed: bool,
+    pub log_file: Option<String>,
+    pub log_json: Option<String>,
+    pub log_format: Option<LogFormat>,
+    pub command: Command,
+}
+
+pub enum Command {
+    Run(Selection),
+    Shrink {
+        puzzle: Puzzle,
+        reference: Vec<String>,
+    },
+    Record {
+        puzzle: Puzzle,
+        submission: Submission,
+    },
+    History(Puzzle),
+    Logs {
+        paths: Vec<String>,
+        query: LogQuery,
+        sort: bool,
+    },
+}
+
+/// Parses the arguments, without the name of the program. The error is a message that is meant
+/// to be shown along with the usage.
+pub fn parse(args: &[String]) -> Result<Args, String> {
+    // arguments of subcommands are passed on as they are, i.e. the reference command of
+    // `shrink`, such that flags are only parsed before them
+    let subcommand = args
+        .iter()
+        .position(|x| SUBCOMMANDS.contains(&x.trim()))
+        .map(|x| x + 1)
+        .unwrap_or(args.len());
+    let mut flags = args[..subcommand].to_vec();
+
+    let porcelain = remove_flag(&mut flags, "--porcelain");
+    let log_dedup = remove_flag(&mut flags, "--log-dedup");
+    let trace_failed = remove_flag(&mut flags, "--trace-failed");
+    let log_file = remove_option(&mut flags, "--log-file")?;
+    let log_json = remove_option(&mut flags, "--log-json")?;
+    let log_format = remove_option(&mut flags, "--log-format")?
+        .map(|x| LogFormat::parse(&x))
+        .transpose()
+        .map_err(|e| format!("invalid log format: {}", e))?;
+
+    flags.extend_from_slice(&args[subcommand..]);
+    let command = parse_command(&flags)?;
+
+    Ok(Args {
+        porcelain,
+        log_dedup,
+        trace_failed,
+        log_file,
+        log_json,
+        log_format,
+        command,
+    })
+}
+
+fn parse_command(args: &[String]) -> Result<Command, String> {
+    let Some(first) = args.first() else {
+        return Err(String::from("too few arguments"));
+    };
+
+    match first.trim() {
+        "shrink" => {
+            let Some(selection) = args.get(1) else {
+                return Err(String::from("too few arguments"));
+            };
+
+            Ok(Command::Shrink {
+                puzzle: parse_puzzle(selection)?,
+                reference: args[2..].to_vec(),
+            })
+        }
+        "record" => {
+            let [_, selection, part, feedback, answer] = args else {
+                return Err(String::from(
+                    "expected a day, a part, a feedback and an answer",
+                ));
+            };
+
+            let puzzle = parse_puzzle(selection)?;
+            if !history::is_valid_part(part.trim()) {
+                return Err(format!("invalid part: {}", part));
+            }
+
+            let Some(feedback) = Feedback::parse(feedback) else {
+                return Err(format!("invalid feedback: {}", feedback));
+            };
+
+            let submission = Submission {
+                part: part.trim().to_string(),
+                feedback,
+                answer: answer.trim().to_string(),
+            };
+
+            Ok(Command::Record { puzzle, submission })
+        }
+        "history" => {
+            let [_, selection] = args else {
+                return Err(String::from("expected a day"));
+            };
+
+            Ok(Command::History(parse_puzzle(selection)?))
+        }
+        "logs" => parse_logs_args(&args[1..]),
+        _ => {
+            if args.len() > 1 {
+                return Err(String::from("too many arguments"));
+            }
+
+            Ok(Command::Run(Selection::parse(first)?))
+        }
+    }
+}
+
+fn parse_logs_args(args: &[String]) -> Result<Command, String> {
+    let mut paths = args.to_vec();
+    let sort = remove_flag(&mut paths, "--sort");
+
+    let mut query = LogQuery {
+        file: remove_option(&mut paths, "--file")?,
+        text: remove_option(&mut paths, "--text")?,
+        ..Default::default()
+    };
+
+    if let Some(level) = remove_option(&mut paths, "--level")? {
+        query.min_level = Some(level.parse()?);
+    }
+
+    for (option, time) in [("--since", &mut query.since), ("--until", &mut query.until)] {
+        if let Some(value) = remove_option(&mut paths, option)? {
+            *time = Some(ris_log::timestamp::parse_iso8601(&value)?);
+        }
+    }
+
+    if paths.is_empty() {
+        return Err(String::from("expected at least one log file"));
+    }
+
+    Ok(Command::Logs { paths, query, sort })
+}
+
+fn parse_puzzle(value: &str) -> Result<Puzzle, String> {
+    match Selection::parse(value)? {
+        Selection::Single(puzzle) => Ok(puzzle),
+        Selection::All(_) => Err(String::from("expected a single day, but got `all`")),
+    }
+}
+
+fn remove_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    let len = args.len();
+    args.retain(|x| x.trim() != flag);
+    args.len() != len
+}
+
+// removes an option, that is followed by a value, like `--log-file <path>`
+fn remove_option(args: &mut Vec<String>, option: &str) -> Result<Option<String>, String> {
+    let Some(index) = args.iter().position(|x| x.trim() == option) else {
+        return Ok(None);
+    };
+
+    args.remove(index);
+    if index < args.len() {
+        Ok(Some(args.remove(index)))
+    } else {
+        Err(format!("missing value for {}", option))
+    }
+}
+
+pub fn usage() -> String {
+    format!(
+        "\
+usage:
+\tcargo run -r <day number>
+\tcargo run -r <year>:<day number>
+\tcargo run -r <event>:<year>:<day number>
+
+pass `all` as day number to run all days
+the event defaults to `{}`, the year to {}
+pass `--porcelain` to print nothing but the answers and to hide progress
+pass `--log-file <path>` to additionally write the log into a file
+pass `--log-json <path>` to additionally write the log as json lines into a file
+pass `--log-dedup` to collapse repeated lines in the console into a single one
+pass `--trace-failed` to rerun days that fail in `all` with every message enabled
+pass `--log-format <template>` to change the layout of log lines, i.e. \"{{time}} {{level:>7}} {{message}}\"
+
+to minimize an input on which a day fails:
+\tcargo run shrink <day number> [reference command]
+
+the reference command is called with the path of the candidate input and must
+print one answer per line. panics can only be caught in debug builds
+
+to record the feedback on a submitted answer:
+\tcargo run record <day number> <part> <correct|too_high|too_low|wrong> <answer>
+
+to list all recorded answers:
+\tcargo run history <day number>
+
+to query a log file, written by `--log-file` or `--log-json`:
+\tcargo run logs <path>... [--level <level>] [--file <text>] [--text <text>] [--since <time>] [--until <time>] [--sort]
+
+times are in utc, i.e. 2025-12-10T14:03:22Z or 2025-12-10. `--sort` orders by the
+sequence number of the messages within each run, instead of the order they were read in",
+        event::DEFAULT_EVENT,
+        event::DEFAULT_YEAR,
+    )
+}
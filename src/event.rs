@@ -0,0 +1,114 @@
+use std::path::PathBuf;
+
+use crate::Solver;
+
+// the event that is used, when a selection doesn't name one
+pub const DEFAULT_EVENT: &str = "aoc";
+pub const DEFAULT_YEAR: u32 = 2025;
+
+pub const EVENTS: &[Event] = &[Event {
+    name: "aoc",
+    year: 2025,
+    solvers: crate::aoc_2025::SOLVERS,
+}];
+
+pub struct Event {
+    pub name: &'static str,
+    pub year: u32,
+    pub solvers: &'static [Solver],
+}
+
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    pub event: &'static Event,
+    pub day: usize,
+}
+
+pub enum Selection {
+    All(&'static Event),
+    Single(Puzzle),
+}
+
+impl Event {
+    pub fn find(name: &str, year: u32) -> Option<&'static Event> {
+        EVENTS.iter().find(|x| x.name == name && x.year == year)
+    }
+
+    pub fn puzzles(&'static self) -> impl Iterator<Item = Puzzle> {
+        (1..=self.solvers.len()).map(move |day| Puzzle { event: self, day })
+    }
+}
+
+impl Puzzle {
+    pub fn solver(&self) -> Solver {
+        self.event.solvers[self.day - 1]
+    }
+
+    // puzzles of the default event are stored as `<year>/day_<n>`, while puzzles of other events
+    // are stored as `<event>/<year>/day_<n>`
+    pub fn key(&self) -> PathBuf {
+        let mut key = PathBuf::new();
+        if self.event.name != DEFAULT_EVENT {
+            key.push(self.event.name);
+        }
+
+        key.push(self.event.year.to_string());
+        key.push(format!("day_{}", self.day));
+        key
+    }
+}
+
+impl std::fmt::Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.event.name != DEFAULT_EVENT {
+            write!(f, "{} ", self.event.name)?;
+        }
+
+        write!(f, "{} day {}", self.event.year, self.day)
+    }
+}
+
+impl Selection {
+    // accepts `<day>`, `<year>:<day>` and `<event>:<year>:<day>`, where `<day>` may be `all`
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let splits = value.trim().split(':').collect::<Vec<_>>();
+        let (event_name, year_str, day_str) = match splits.as_slice() {
+            [day] => (DEFAULT_EVENT, None, *day),
+            [year, day] => (DEFAULT_EVENT, Some(*year), *day),
+            [event, year, day] => (*event, Some(*year), *day),
+            _ => return Err(format!("invalid selection: {}", value)),
+        };
+
+        let year = match year_str {
+            Some(year_str) => match year_str.parse::<u32>() {
+                Ok(year) => year,
+                Err(_) => return Err(format!("invalid year: {}", year_str)),
+            },
+            None => DEFAULT_YEAR,
+        };
+
+        let Some(event) = Event::find(event_name, year) else {
+            return Err(format!("no solvers exist for {} {}", event_name, year));
+        };
+
+        if day_str == "all" {
+            return Ok(Selection::All(event));
+        }
+
+        let day = match day_str.parse::<usize>() {
+            Ok(day) => day,
+            Err(_) => return Err(format!("invalid day number: {}", day_str)),
+        };
+
+        let min = 1;
+        let max = event.solvers.len();
+        if day < min || day > max {
+            return Err(format!(
+                "expected day number to be between {} and {}, but was {}",
+                min, max, day
+            ));
+        }
+
+        Ok(Selection::Single(Puzzle { event, day }))
+    }
+}
@@ -0,0 +1,183 @@
+use std::io::Write;
+use std::path::PathBuf;
+
+use ris_error::prelude::*;
+
+use crate::event::Puzzle;
+
+pub const HISTORY_PATH: &str = "answer_history";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feedback {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub part: String,
+    pub feedback: Feedback,
+    pub answer: String,
+}
+
+impl Feedback {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Feedback::Correct => "correct",
+            Feedback::TooHigh => "too_high",
+            Feedback::TooLow => "too_low",
+            Feedback::Wrong => "wrong",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "correct" => Some(Feedback::Correct),
+            "too_high" | "high" => Some(Feedback::TooHigh),
+            "too_low" | "low" => Some(Feedback::TooLow),
+            "wrong" => Some(Feedback::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Feedback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            Feedback::Correct => "correct",
+            Feedback::TooHigh => "too high",
+            Feedback::TooLow => "too low",
+            Feedback::Wrong => "wrong",
+        };
+
+        write!(f, "{}", description)
+    }
+}
+
+// the history of a puzzle is stored in the same path as its input, with one submission per line:
+// `<part> <feedback> <answer>`
+pub fn path(puzzle: Puzzle) -> PathBuf {
+    PathBuf::from(HISTORY_PATH).join(puzzle.key())
+}
+
+pub fn load(puzzle: Puzzle) -> RisResult<Vec<Submission>> {
+    let path = path(puzzle);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = std::fs::read_to_string(&path)?;
+
+    let mut submissions = Vec::new();
+    for (i, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut splits = line.splitn(3, ' ');
+        let part = splits.next().into_ris_error()?;
+        let feedback_str = splits.next().into_ris_error()?;
+        let answer = splits.next().into_ris_error()?;
+
+        let Some(feedback) = Feedback::parse(feedback_str) else {
+            return ris_error::new_result!(
+                "invalid feedback \"{}\" in {}:{}",
+                feedback_str,
+                path.display(),
+                i + 1,
+            );
+        };
+
+        submissions.push(Submission {
+            part: part.to_string(),
+            feedback,
+            answer: answer.to_string(),
+        });
+    }
+
+    Ok(submissions)
+}
+
+// parts are numbered like the answers, i.e. "1: 42". `check` never matches any other part
+pub fn is_valid_part(part: &str) -> bool {
+    !part.is_empty() && part.chars().all(|x| x.is_ascii_digit())
+}
+
+pub fn record(puzzle: Puzzle, submission: &Submission) -> RisResult<()> {
+    if !is_valid_part(&submission.part) {
+        return ris_error::new_result!("invalid part \"{}\", expected a number", submission.part);
+    }
+
+    let path = path(puzzle);
+    if let Some(directory) = path.parent() {
+        std::fs::create_dir_all(directory)?;
+    }
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)?;
+
+    writeln!(
+        file,
+        "{} {} {}",
+        submission.part,
+        submission.feedback.as_str(),
+        submission.answer,
+    )?;
+
+    Ok(())
+}
+
+// returns a warning for every submission that proves `answer` to be wrong
+pub fn check(history: &[Submission], part: &str, answer: &str) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let value = answer.trim().parse::<i128>().ok();
+
+    for submission in history.iter().filter(|x| x.part == part) {
+        let submitted_value = submission.answer.trim().parse::<i128>().ok();
+
+        match submission.feedback {
+            Feedback::Correct => {
+                if submission.answer != answer {
+                    warnings.push(format!(
+                        "part {}: {} differs from the correct answer {}",
+                        part, answer, submission.answer,
+                    ));
+                }
+            }
+            feedback if submission.answer == answer => {
+                warnings.push(format!(
+                    "part {}: {} was already submitted and was {}",
+                    part, answer, feedback,
+                ));
+            }
+            Feedback::TooHigh => {
+                if let (Some(value), Some(bound)) = (value, submitted_value)
+                    && value > bound
+                {
+                    warnings.push(format!(
+                        "part {}: {} is higher than {}, which is known to be too high",
+                        part, answer, submission.answer,
+                    ));
+                }
+            }
+            Feedback::TooLow => {
+                if let (Some(value), Some(bound)) = (value, submitted_value)
+                    && value < bound
+                {
+                    warnings.push(format!(
+                        "part {}: {} is lower than {}, which is known to be too low",
+                        part, answer, submission.answer,
+                    ));
+                }
+            }
+            Feedback::Wrong => (),
+        }
+    }
+
+    warnings
+}
@@ -0,0 +1,43 @@
+pub mod aoc_2025;
+pub mod cli;
+pub mod event;
+pub mod history;
+pub mod runner;
+pub mod shrink;
+
+use std::io::Read;
+use std::path::Path;
+use std::path::PathBuf;
+
+use ris_error::prelude::*;
+
+pub const PUZZLE_INPUT_PATH: &str = "puzzle_input";
+
+pub type Solver = fn(&str, &mut Answer) -> RisResult<()>;
+
+pub fn read_puzzle_input(key: impl AsRef<Path>) -> RisResult<String> {
+    let path = PathBuf::from(PUZZLE_INPUT_PATH).join(key.as_ref());
+    let mut file = std::fs::File::open(path)?;
+    let mut content = String::new();
+    file.read_to_string(&mut content)?;
+    Ok(content)
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct Answer(pub Vec<String>);
+
+impl Answer {
+    pub fn add(&mut self, message: impl AsRef<str>) {
+        self.0.push(message.as_ref().to_string());
+    }
+}
+
+// answers are added like "1: 42". this splits such a message into its part and its value
+pub fn split_answer(message: &str) -> Option<(&str, &str)> {
+    let (part, value) = message.split_once(": ")?;
+    if !history::is_valid_part(part) {
+        return None;
+    }
+
+    Some((part, value))
+}
@@ -1,145 +1,162 @@
-mod day_1;
-mod day_2;
-mod day_3;
-mod day_4;
-mod day_5;
-mod day_6;
-mod day_7;
-mod day_8;
-mod day_9;
-mod day_10;
-
-use std::io::Read;
-use std::path::PathBuf;
-
 use ris_error::prelude::*;
-use ris_log::constructed_log_message::ConstructedLogFormatArgs;
+use ris_log::console_appender::ConsoleAppender;
+use ris_log::dedup_appender::DedupAppender;
+use ris_log::file_appender::FileAppender;
+use ris_log::json_lines_appender::JsonLinesAppender;
 use ris_log::log::IAppender;
 use ris_log::log::LogGuard;
 use ris_log::log_level::LogLevel;
-use ris_log::log_message::LogMessage;
+use ris_log::progress::ProgressMode;
 
-const PUZZLE_INPUT_PATH: &str = "puzzle_input";
+use advent_of_code_2025::cli;
+use advent_of_code_2025::cli::Args;
+use advent_of_code_2025::cli::Command;
+use advent_of_code_2025::history;
+use advent_of_code_2025::runner;
+use advent_of_code_2025::shrink;
+use advent_of_code_2025::Answer;
 
 const LOG_LEVEL: LogLevel = LogLevel::Trace;
+const LOG_FILE_MAX_SIZE: u64 = 10 * 1024 * 1024;
+const LOG_FILE_BACKUPS: usize = 3;
 
-const SOLVERS: &[fn(&mut Answer) -> RisResult<()>] = &[
-    day_1::run,
-    day_2::run,
-    day_3::run,
-    day_4::run,
-    day_5::run,
-    day_6::run,
-    day_7::run,
-    day_8::run,
-    day_9::run,
-    day_10::run,
-];
-
-struct ConsoleAppender;
-
-impl IAppender for ConsoleAppender {
-    fn print(&mut self, message: &LogMessage) {
-        let args = ConstructedLogFormatArgs {
-            ansi_support: true,
-            show_timestamp: false,
-            show_priority: true,
-            show_foot: false,
-        };
-        let message_string = message.fmt(args);
-        eprintln!("{}", message_string);
-    }
-}
+fn main() -> RisResult<()> {
+    let start = std::time::Instant::now();
 
-pub fn read_puzzle_input(key: impl AsRef<str>) -> RisResult<String> {
-    let path = PathBuf::from(PUZZLE_INPUT_PATH).join(key.as_ref());
-    let mut file = std::fs::File::open(path)?;
-    let mut content = String::new();
-    file.read_to_string(&mut content)?;
-    Ok(content)
-}
+    // invalid arguments are reported once the log is initialized
+    let raw_args = std::env::args().skip(1).collect::<Vec<_>>();
+    let args = cli::parse(&raw_args);
 
-#[derive(Default)]
-struct Answer(Vec<String>);
+    let log_guard = init_log(args.as_ref().ok())?;
+    ris_log::panic_hook::install(env!("CARGO_PKG_NAME"), false);
 
-impl Answer {
-    pub fn add(&mut self, message: impl AsRef<str>) {
-        self.0.push(message.as_ref().to_string());
-    }
-}
+    let args = match args {
+        Ok(args) => args,
+        Err(message) => return print_usage(log_guard, message),
+    };
 
-fn main() -> RisResult<()> {
-    let start = std::time::Instant::now();
+    match args.command {
+        Command::Run(selection) => {
+            let mut answer = Answer::default();
+            runner::run(selection, args.trace_failed, &mut answer)?;
 
-    // init logging
-    let console_appender = Box::new(ConsoleAppender);
-    let appenders: Vec<Box<dyn IAppender + Send>> = vec![console_appender];
-    let log_guard = ris_log::log::init(LOG_LEVEL, appenders);
+            drop(log_guard);
+            if !args.porcelain {
+                eprintln!();
+                eprintln!("answers:");
+            }
 
-    // parse args
-    let raw_args = std::env::args().collect::<Vec<_>>();
-    if raw_args.len() < 2 {
-        return print_usage(log_guard, "too few arguments");
-    }
+            for message in answer.0 {
+                println!("{}", message);
+            }
 
-    if raw_args.len() > 2 {
-        return print_usage(log_guard, "too many arguments");
-    }
+            if !args.porcelain {
+                print_time(start);
+            }
+        }
+        Command::Shrink { puzzle, reference } => {
+            ris_log::info!("read input...");
+            let input = advent_of_code_2025::read_puzzle_input(puzzle.key())?;
 
-    let day = raw_args[1].trim();
-
-    // run
-    let mut answer = Answer::default();
-
-    match day {
-        "all" => {
-            for (i, solver) in SOLVERS.iter().enumerate() {
-                let day_number = i + 1;
-                ris_log::info!("run day {}...", day_number);
-                answer.add(format!("day {}:", day_number));
-                if let Err(e) = solver(&mut answer) {
-                    ris_log::error!("day {} failed: {:?}", day_number, e);
-                    answer.add(format!("error: {}", e.message));
-                };
-                answer.add(String::new());
+            ris_log::info!("shrink {}...", puzzle);
+            let path = shrink::run(puzzle, &input, &reference)?;
+
+            drop(log_guard);
+            eprintln!();
+            eprintln!("shrunk input written to {}", path.display());
+
+            print_time(start);
+        }
+        Command::Record { puzzle, submission } => {
+            history::record(puzzle, &submission)?;
+            ris_log::info!(
+                "recorded {} part {}: {} was {}",
+                puzzle,
+                submission.part,
+                submission.answer,
+                submission.feedback,
+            );
+        }
+        Command::History(puzzle) => {
+            let submissions = history::load(puzzle)?;
+
+            drop(log_guard);
+            eprintln!("submissions of {}:", puzzle);
+            for submission in submissions {
+                println!(
+                    "{}: {} was {}",
+                    submission.part, submission.answer, submission.feedback,
+                );
             }
         }
-        number_str => {
-            let number = match number_str.parse::<usize>() {
-                Ok(number) => number,
-                Err(_) => return print_usage(log_guard, format!("invalid day number: {}", day)),
+        Command::Logs { paths, query, sort } => {
+            let mut messages = Vec::new();
+            for path in paths.iter() {
+                match ris_log::log_reader::read(path) {
+                    Ok(read) => messages.extend(read),
+                    Err(message) => return print_usage(log_guard, message),
+                }
+            }
+
+            // sorting needs the marks of the runs, which the query may remove
+            if sort {
+                ris_log::log_reader::sort_by_counter(&mut messages);
+            }
+            messages.retain(|x| query.matches(x));
+
+            drop(log_guard);
+            let format_args = ris_log::constructed_log_message::ConstructedLogFormatArgs {
+                ansi_support: ris_log::color_string::stdout_supports_ansi(),
+                ..ris_log::file_appender::DEFAULT_FORMAT_ARGS
             };
-            let min = 1;
-            let max = SOLVERS.len();
-            if number < min || number > max {
-                return print_usage(
-                    log_guard,
-                    format!(
-                        "expected day number to be between {} and {}, but was {}",
-                        min, max, number
-                    ),
-                );
+
+            for message in messages {
+                println!("{}", message.fmt(format_args));
             }
-            let solver = SOLVERS[number - 1];
-            solver(&mut answer)?
         }
     }
 
-    // print output
-    drop(log_guard);
-    eprintln!();
-    eprintln!("answers:");
-    for message in answer.0 {
-        println!("{}", message);
+    Ok(())
+}
+
+// without valid arguments, only the console is logged to
+fn init_log(args: Option<&Args>) -> RisResult<LogGuard> {
+    let porcelain = args.is_some_and(|x| x.porcelain);
+    if porcelain {
+        ris_log::progress::set_mode(ProgressMode::Hidden);
+    }
+
+    let log_format = args.and_then(|x| x.log_format.clone());
+    let console_appender = ConsoleAppender {
+        format: log_format.clone(),
+        ..Default::default()
+    };
+    let console_appender: Box<dyn IAppender + Send> = if args.is_some_and(|x| x.log_dedup) {
+        Box::new(DedupAppender::new(Box::new(console_appender)))
+    } else {
+        Box::new(console_appender)
+    };
+    let mut appenders = vec![console_appender];
+
+    if let Some(log_file) = args.and_then(|x| x.log_file.as_ref()) {
+        let mut file_appender = FileAppender::new(log_file, LOG_FILE_MAX_SIZE, LOG_FILE_BACKUPS)?;
+        file_appender.format = log_format;
+        appenders.push(Box::new(file_appender));
+    }
+
+    if let Some(log_json) = args.and_then(|x| x.log_json.as_ref()) {
+        let json_lines_appender = JsonLinesAppender::create(log_json)?;
+        appenders.push(Box::new(json_lines_appender));
     }
 
-    // print time
+    Ok(ris_log::log::init(LOG_LEVEL, appenders))
+}
+
+fn print_time(start: std::time::Instant) {
     let end = std::time::Instant::now();
     let duration = end - start;
     eprintln!();
     eprintln!("done! time elapsed: {:?}", duration);
-
-    Ok(())
 }
 
 fn print_usage(log_guard: LogGuard, message: impl AsRef<str>) -> RisResult<()> {
@@ -147,10 +164,7 @@ fn print_usage(log_guard: LogGuard, message: impl AsRef<str>) -> RisResult<()> {
     drop(log_guard);
 
     eprintln!();
-    eprintln!("usage:");
-    eprintln!("\tcargo run -r <day number>");
-    eprintln!();
-    eprintln!("pass `all` to run all days");
+    eprintln!("{}", cli::usage());
 
     Ok(())
 }
@@ -0,0 +1,95 @@
+use ris_error::prelude::*;
+use ris_log::log_filter::LogFilter;
+use ris_log::log_level::LogLevel;
+
+use crate::event::Puzzle;
+use crate::event::Selection;
+use crate::Answer;
+
+pub fn run(selection: Selection, trace_failed: bool, answer: &mut Answer) -> RisResult<()> {
+    match selection {
+        Selection::All(event) => {
+            // a failing day should not prevent the others from running
+            for puzzle in event.puzzles() {
+                answer.add(format!("{}:", puzzle));
+                if let Err(e) = run_puzzle(puzzle, answer) {
+                    ris_log::error!("{} failed: {:?}", puzzle, e);
+                    answer.add(format!("error: {}", e.message));
+                    if trace_failed {
+                        trace_failed_puzzle(puzzle);
+                    }
+                };
+                answer.add(String::new());
+            }
+
+            Ok(())
+        }
+        Selection::Single(puzzle) => run_puzzle(puzzle, answer),
+    }
+}
+
+pub fn run_puzzle(puzzle: Puzzle, answer: &mut Answer) -> RisResult<()> {
+    let _span = ris_log::span!("{}", puzzle);
+
+    ris_log::info!("read input...");
+    let input = crate::read_puzzle_input(puzzle.key())?;
+
+    let first_message = answer.0.len();
+    let start = std::time::Instant::now();
+    let solver = puzzle.solver();
+    solver(&input, answer)?;
+
+    ris_log::info!(
+        event = puzzle.event.name,
+        year = puzzle.event.year,
+        day = puzzle.day,
+        elapsed = start.elapsed();
+        "solved {}",
+        puzzle,
+    );
+
+    warn_known_wrong_answers(puzzle, &answer.0[first_message..]);
+
+    Ok(())
+}
+
+// reruns a failed puzzle with every message enabled, such that the failure can be investigated
+// without tracing all other puzzles. this runs the puzzle twice, so it must be requested
+fn trace_failed_puzzle(puzzle: Puzzle) {
+    let trace_filter = LogFilter::from(LogLevel::Trace);
+    match ris_log::log::filter() {
+        Some(filter) if filter != trace_filter => (),
+        // already traced, or not logging at all
+        _ => return,
+    }
+
+    let _filter_guard = ris_log::log::scoped_filter(trace_filter);
+    ris_log::info!("rerun {} with tracing enabled...", puzzle);
+    let mut answer = Answer::default();
+    let _ = run_puzzle(puzzle, &mut answer);
+}
+
+// this only warns. as such, a history that cannot be read doesn't fail the puzzle
+fn warn_known_wrong_answers(puzzle: Puzzle, messages: &[String]) {
+    let history = match crate::history::load(puzzle) {
+        Ok(history) => history,
+        Err(e) => {
+            ris_log::warning!("{}: couldn't read the answer history: {}", puzzle, e);
+            return;
+        }
+    };
+
+    if history.is_empty() {
+        return;
+    }
+
+    for message in messages {
+        let Some((part, value)) = crate::split_answer(message) else {
+            continue;
+        };
+
+        for warning in crate::history::check(&history, part, value) {
+            ris_log::warning!("{}: {}", puzzle, warning);
+        }
+    }
+}
@@ -0,0 +1,312 @@
+use std::io::Write;
+use std::panic::AssertUnwindSafe;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use ris_error::prelude::*;
+
+use crate::event::Puzzle;
+use crate::Answer;
+
+const SHRINK_OUTPUT_PATH: &str = "shrunk_input";
+
+/// Two failures are considered the same, if they are of the same kind and occur at the same
+/// location. The messages are only kept for display, since they often contain values of the
+/// input, which change while shrinking.
+#[derive(Debug, Clone)]
+pub enum Failure {
+    Error {
+        source_type_name: Option<String>,
+        location: String,
+        message: String,
+    },
+    Panic {
+        location: String,
+        message: String,
+    },
+    Mismatch,
+}
+
+impl PartialEq for Failure {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Failure::Error {
+                    source_type_name: left_type,
+                    location: left_location,
+                    ..
+                },
+                Failure::Error {
+                    source_type_name: right_type,
+                    location: right_location,
+                    ..
+                },
+            ) => left_type == right_type && left_location == right_location,
+            (
+                Failure::Panic {
+                    location: left_location,
+                    ..
+                },
+                Failure::Panic {
+                    location: right_location,
+                    ..
+                },
+            ) => left_location == right_location,
+            (Failure::Mismatch, Failure::Mismatch) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Failure {}
+
+impl std::fmt::Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Error {
+                source_type_name,
+                location,
+                message,
+            } => {
+                write!(f, "error")?;
+                if let Some(source_type_name) = source_type_name {
+                    write!(f, " from {}", source_type_name)?;
+                }
+                write!(f, " at {}: \"{}\"", location, message)
+            }
+            Failure::Panic { location, message } => {
+                write!(f, "panic at {}: \"{}\"", location, message)
+            }
+            Failure::Mismatch => write!(f, "answers disagree with the reference"),
+        }
+    }
+}
+
+// the location of the first panic during a check. the panic hook is the only place it is known
+static PANIC_LOCATION: Mutex<Option<String>> = Mutex::new(None);
+
+/// Repeatedly removes lines and then tokens from `input`, as long as the solver of `puzzle` keeps
+/// failing in the same way as it does on the original input. The minimized input is written to
+/// `shrunk_input`, using the same path as the puzzle input.
+///
+/// `reference` is an optional command, which is called with the path to a candidate input as
+/// its last argument. It must print one answer per line. If it is provided, answers that
+/// disagree with the reference count as a failure.
+pub fn run(puzzle: Puzzle, input: &str, reference: &[String]) -> RisResult<PathBuf> {
+    // panics are expected while shrinking. silence them, so they don't flood stderr
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|info| {
+        let Ok(mut panic_location) = PANIC_LOCATION.lock() else {
+            return;
+        };
+
+        // solvers that spawn threads panic again when joining them. the original panic is the
+        // one of interest
+        if panic_location.is_none() {
+            *panic_location = info.location().map(|x| x.to_string());
+        }
+    }));
+    let result = shrink(puzzle, input, reference);
+    std::panic::set_hook(previous_hook);
+
+    let shrunk_input = result?;
+
+    let path = PathBuf::from(SHRINK_OUTPUT_PATH).join(puzzle.key());
+    if let Some(directory) = path.parent() {
+        std::fs::create_dir_all(directory)?;
+    }
+    let mut file = std::fs::File::create(&path)?;
+    file.write_all(shrunk_input.as_bytes())?;
+
+    Ok(path)
+}
+
+fn shrink(puzzle: Puzzle, input: &str, reference: &[String]) -> RisResult<String> {
+    ris_log::info!("check original input...");
+    let Some(failure) = check(puzzle, input, reference)? else {
+        return ris_error::new_result!("{} does not fail on the given input", puzzle);
+    };
+    ris_log::info!("failure to preserve: {}", failure);
+
+    let mut attempts = 0;
+    let mut is_failing = |candidate: &str| -> RisResult<bool> {
+        attempts += 1;
+        let candidate_failure = check(puzzle, candidate, reference)?;
+        Ok(candidate_failure.as_ref() == Some(&failure))
+    };
+
+    ris_log::info!("remove lines...");
+    let lines = input.split_inclusive('\n').map(String::from).collect();
+    let lines = ddmin(lines, &mut is_failing)?;
+
+    ris_log::info!("remove tokens...");
+    let shrunk_input = lines.concat();
+    let tokens = shrunk_input
+        .split_inclusive([' ', ',', '\n'])
+        .map(String::from)
+        .collect();
+    let tokens = ddmin(tokens, &mut is_failing)?;
+
+    let shrunk_input = tokens.concat();
+    ris_log::info!(
+        "shrunk input from {} to {} bytes in {} attempts",
+        input.len(),
+        shrunk_input.len(),
+        attempts,
+    );
+
+    Ok(shrunk_input)
+}
+
+fn ddmin(
+    mut units: Vec<String>,
+    is_failing: &mut impl FnMut(&str) -> RisResult<bool>,
+) -> RisResult<Vec<String>> {
+    let mut granularity = 2;
+
+    while units.len() >= 2 {
+        let chunk_size = units.len().div_ceil(granularity);
+
+        let mut was_reduced = false;
+        let mut start = 0;
+        while start < units.len() {
+            let end = usize::min(start + chunk_size, units.len());
+
+            // try the complement, e.g. the input without the current chunk
+            let complement = units[..start]
+                .iter()
+                .chain(units[end..].iter())
+                .cloned()
+                .collect::<Vec<_>>();
+
+            if is_failing(&complement.concat())? {
+                ris_log::debug!(
+                    "removed {} units, {} remaining",
+                    end - start,
+                    complement.len()
+                );
+                units = complement;
+                granularity = usize::max(granularity - 1, 2);
+                was_reduced = true;
+                break;
+            }
+
+            start = end;
+        }
+
+        if was_reduced {
+            continue;
+        }
+
+        if granularity >= units.len() {
+            // every single unit is required to reproduce the failure
+            break;
+        }
+
+        granularity = usize::min(granularity * 2, units.len());
+    }
+
+    Ok(units)
+}
+
+fn check(puzzle: Puzzle, input: &str, reference: &[String]) -> RisResult<Option<Failure>> {
+    let solver = puzzle.solver();
+    let mut answer = Answer::default();
+    if let Ok(mut panic_location) = PANIC_LOCATION.lock() {
+        *panic_location = None;
+    }
+    let result = std::panic::catch_unwind(AssertUnwindSafe(|| solver(input, &mut answer)));
+
+    match result {
+        Err(payload) => {
+            let message = if let Some(message) = payload.downcast_ref::<&str>() {
+                message.to_string()
+            } else if let Some(message) = payload.downcast_ref::<String>() {
+                message.clone()
+            } else {
+                String::from("unknown panic payload")
+            };
+
+            let location = PANIC_LOCATION
+                .lock()
+                .ok()
+                .and_then(|x| x.clone())
+                .unwrap_or_else(|| String::from("unknown location"));
+
+            Ok(Some(Failure::Panic { location, message }))
+        }
+        Ok(Err(e)) => Ok(Some(Failure::Error {
+            source_type_name: e.source_type_name,
+            location: format!("{}:{}", e.file, e.line),
+            message: e.message,
+        })),
+        Ok(Ok(())) => {
+            if reference.is_empty() {
+                return Ok(None);
+            }
+
+            // an input the reference fails on is not a valid input. as such it cannot reproduce
+            // the mismatch
+            let Some(expected) = run_reference(puzzle, input, reference)? else {
+                return Ok(None);
+            };
+
+            let actual = answer
+                .0
+                .iter()
+                .map(|x| strip_part(x).to_string())
+                .collect::<Vec<_>>();
+
+            if actual == expected {
+                Ok(None)
+            } else {
+                Ok(Some(Failure::Mismatch))
+            }
+        }
+    }
+}
+
+fn run_reference(
+    puzzle: Puzzle,
+    input: &str,
+    reference: &[String],
+) -> RisResult<Option<Vec<String>>> {
+    let path = std::env::temp_dir().join(format!(
+        "advent_of_code_shrink_{}_{}_day_{}_{}",
+        puzzle.event.name,
+        puzzle.event.year,
+        puzzle.day,
+        std::process::id(),
+    ));
+    std::fs::write(&path, input)?;
+
+    let program = reference.first().into_ris_error()?;
+    let output = std::process::Command::new(program)
+        .args(&reference[1..])
+        .arg(&path)
+        .output();
+    let _ = std::fs::remove_file(&path);
+    let output = output?;
+
+    if !output.status.success() {
+        return Ok(None);
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let answers = stdout
+        .lines()
+        .map(|x| x.trim())
+        .filter(|x| !x.is_empty())
+        .map(|x| strip_part(x).to_string())
+        .collect();
+
+    Ok(Some(answers))
+}
+
+// only the values are compared, such that the reference doesn't need to print the parts
+fn strip_part(answer: &str) -> &str {
+    match crate::split_answer(answer) {
+        Some((_, value)) => value,
+        None => answer,
+    }
+}
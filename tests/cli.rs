@@ -0,0 +1,111 @@
+use advent_of_code_2025::cli;
+use advent_of_code_2025::cli::Command;
+use advent_of_code_2025::event::Selection;
+use advent_of_code_2025::history::Feedback;
+use ris_log::log_level::LogLevel;
+
+fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|x| x.to_string()).collect()
+}
+
+fn parse_error(raw_args: &[&str]) -> String {
+    match cli::parse(&args(raw_args)) {
+        Ok(_) => panic!("expected {:?} to be rejected", raw_args),
+        Err(message) => message,
+    }
+}
+
+#[test]
+fn flags_are_parsed_around_the_selection() {
+    let parsed = cli::parse(&args(&["--porcelain", "5", "--log-file", "out.log"])).unwrap();
+
+    assert!(parsed.porcelain);
+    assert!(!parsed.log_dedup);
+    assert!(!parsed.trace_failed);
+    assert_eq!(parsed.log_file.as_deref(), Some("out.log"));
+    assert!(parsed.log_json.is_none());
+
+    let Command::Run(Selection::Single(puzzle)) = parsed.command else {
+        panic!("expected a single puzzle to run");
+    };
+    assert_eq!(puzzle.event.year, 2025);
+    assert_eq!(puzzle.day, 5);
+}
+
+#[test]
+fn all_selects_every_puzzle() {
+    let parsed = cli::parse(&args(&["all", "--trace-failed"])).unwrap();
+
+    assert!(parsed.trace_failed);
+    assert!(matches!(parsed.command, Command::Run(Selection::All(_))));
+}
+
+#[test]
+fn arguments_after_a_subcommand_are_passed_on() {
+    let raw_args = args(&["--log-dedup", "shrink", "5", "./reference", "--porcelain"]);
+    let parsed = cli::parse(&raw_args).unwrap();
+
+    assert!(parsed.log_dedup);
+    assert!(!parsed.porcelain);
+
+    let Command::Shrink { puzzle, reference } = parsed.command else {
+        panic!("expected shrink");
+    };
+    assert_eq!(puzzle.day, 5);
+    assert_eq!(reference, args(&["./reference", "--porcelain"]));
+}
+
+#[test]
+fn record_builds_a_submission() {
+    let parsed = cli::parse(&args(&["record", "2025:3", "1", "too_high", "1234"])).unwrap();
+
+    let Command::Record { puzzle, submission } = parsed.command else {
+        panic!("expected record");
+    };
+    assert_eq!(puzzle.day, 3);
+    assert_eq!(submission.part, "1");
+    assert_eq!(submission.feedback, Feedback::TooHigh);
+    assert_eq!(submission.answer, "1234");
+}
+
+#[test]
+fn logs_builds_a_query() {
+    let raw_args = args(&["logs", "a.log", "--level", "warning", "b.log", "--sort"]);
+    let parsed = cli::parse(&raw_args).unwrap();
+
+    let Command::Logs { paths, query, sort } = parsed.command else {
+        panic!("expected logs");
+    };
+    assert_eq!(paths, args(&["a.log", "b.log"]));
+    assert_eq!(query.min_level, Some(LogLevel::Warning));
+    assert!(sort);
+}
+
+#[test]
+fn invalid_arguments_are_rejected() {
+    assert_eq!(parse_error(&[]), "too few arguments");
+    assert_eq!(parse_error(&["5", "6"]), "too many arguments");
+    assert_eq!(
+        parse_error(&["5", "--log-file"]),
+        "missing value for --log-file"
+    );
+    assert_eq!(parse_error(&["history"]), "expected a day");
+    assert_eq!(
+        parse_error(&["history", "all"]),
+        "expected a single day, but got `all`"
+    );
+    assert_eq!(parse_error(&["logs"]), "expected at least one log file");
+    assert_eq!(
+        parse_error(&["record", "5", "1", "close", "7"]),
+        "invalid feedback: close"
+    );
+    assert_eq!(
+        parse_error(&["record", "5", "one", "wrong", "7"]),
+        "invalid part: one"
+    );
+    assert_eq!(
+        parse_error(&["record", "5", "1a", "wrong", "7"]),
+        "invalid part: 1a"
+    );
+    assert!(parse_error(&["5", "--log-format", "{nope}"]).starts_with("invalid log format: "));
+}
@@ -0,0 +1,18 @@
+use advent_of_code_2025::aoc_2025::day_9::Aabb;
+use advent_of_code_2025::aoc_2025::day_9::Vec2;
+
+#[test]
+fn aabb_is_built_from_any_two_corners() {
+    let aabb = Aabb::new(Vec2(9, 7), Vec2(2, 3));
+
+    assert_eq!(aabb.min.0, 2);
+    assert_eq!(aabb.min.1, 3);
+    assert_eq!(aabb.max.0, 9);
+    assert_eq!(aabb.max.1, 7);
+}
+
+#[test]
+fn aabb_area_includes_both_corners() {
+    assert_eq!(Aabb::new(Vec2(2, 5), Vec2(11, 1)).area(), 50);
+    assert_eq!(Aabb::new(Vec2(4, 4), Vec2(4, 4)).area(), 1);
+}
@@ -0,0 +1,44 @@
+// without logging, no message is captured
+#![cfg(feature = "logging_enabled")]
+
+use ris_log::field::FieldValue;
+use ris_log::log_level::LogLevel;
+
+#[test]
+fn creating_an_error_warns_about_the_backtrace() {
+    let capture = ris_log::capture_appender::start();
+
+    let _ = ris_error::new!("test error");
+
+    ris_log::assert_logged!(Warning, contains "created backtrace");
+
+    let messages = capture.messages();
+    let warning = messages
+        .iter()
+        .find(|x| x.message.starts_with("created backtrace"))
+        .unwrap();
+    assert_eq!(warning.priority, LogLevel::Warning);
+    assert_eq!(warning.file, file!());
+    assert!(warning.fields.is_empty());
+}
+
+#[test]
+fn leaving_a_span_logs_the_elapsed_time() {
+    let capture = ris_log::capture_appender::start();
+
+    {
+        let _span = ris_log::span!("test span");
+    }
+
+    ris_log::assert_logged!(Info, contains "exit test span");
+
+    let messages = capture.messages();
+    let exit = messages
+        .iter()
+        .find(|x| x.message == "exit test span")
+        .unwrap();
+    assert_eq!(exit.priority, LogLevel::Info);
+    assert_eq!(exit.fields.len(), 1);
+    assert_eq!(exit.fields[0].key, "elapsed");
+    assert!(matches!(exit.fields[0].value, FieldValue::Duration(_)));
+}